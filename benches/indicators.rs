@@ -1,14 +1,37 @@
 use bencher::{benchmark_group, benchmark_main, black_box, Bencher};
 use rand::Rng;
 use ta::indicators::{
-    AverageTrueRange, BollingerBands, ChandelierExit, CommodityChannelIndex, EfficiencyRatio,
-    ExponentialMovingAverage, FastStochastic, KeltnerChannel, Maximum, MeanAbsoluteDeviation,
-    Minimum, MoneyFlowIndex, MovingAverageConvergenceDivergence, OnBalanceVolume,
-    PercentagePriceOscillator, RateOfChange, RelativeStrengthIndex, SimpleMovingAverage,
-    SlowStochastic, StandardDeviation, TrueRange, WeightedMovingAverage,
+    ArgMaximum, ArgMinimum, ArnaudLegouxMovingAverage, Aroon, AverageTrueRange,
+    AverageTrueRangePercentRank, BollingerBands, ChandelierExit, CommodityChannelIndex,
+    DonchianChannel, DoubleExponentialMovingAverage, EfficiencyRatio, ExponentialMovingAverage,
+    FastStochastic, FractalAdaptiveMovingAverage, FullStochastic, HullMovingAverage,
+    KeltnerChannel, LogReturn, Maximum, McGinleyDynamic, MeanAbsoluteDeviation, Median,
+    MedianAbsoluteDeviation, Minimum, Momentum, MoneyFlowIndex, MovingAverageConvergenceDivergence,
+    NormalizedAverageTrueRange, OnBalanceVolume, PercentagePriceOscillator, RateOfChange,
+    RateOfChangePercentage, RateOfChangeRatio, RateOfChangeRatio100, RelativeStrengthIndex,
+    SimpleMovingAverage, SlowStochastic, SmoothedMovingAverage, Squeeze, StandardDeviation,
+    StochasticRsi, Supertrend, T3MovingAverage, TripleExponentialMovingAverage, Trix, TrueRange,
+    VariableIndexDynamicAverage, Variance, VolumeWeightedMovingAverage, WeightedMovingAverage,
+    WilliamsR, ZeroLagExponentialMovingAverage,
+};
+use ta::indicators::{
+    InlineMaximum, InlineMeanAbsoluteDeviation, InlineMedian, InlineMedianAbsoluteDeviation,
+    InlineMinimum, InlineSimpleMovingAverage, InlineStandardDeviation, InlineVariance,
+    InlineWeightedMovingAverage,
 };
 use ta::{DataItem, Next};
 
+// Inline variants with the same period as the default of their heap counterparts
+type InlineSma = InlineSimpleMovingAverage<9>;
+type InlineWma = InlineWeightedMovingAverage<9>;
+type InlineSd = InlineStandardDeviation<9>;
+type InlineVar = InlineVariance<9>;
+type InlineMad = InlineMeanAbsoluteDeviation<9>;
+type InlineMed = InlineMedian<9>;
+type InlineMedAd = InlineMedianAbsoluteDeviation<9>;
+type InlineMax = InlineMaximum<14>;
+type InlineMin = InlineMinimum<14>;
+
 const ITEMS_COUNT: usize = 5_000;
 
 fn rand_data_item() -> DataItem {
@@ -52,26 +75,66 @@ macro_rules! bench_indicators {
 }
 
 bench_indicators!(
+    ArgMaximum,
+    ArgMinimum,
+    Aroon,
     AverageTrueRange,
+    AverageTrueRangePercentRank,
     ExponentialMovingAverage,
+    DoubleExponentialMovingAverage,
+    TripleExponentialMovingAverage,
+    Trix,
     MeanAbsoluteDeviation,
+    InlineMad,
+    Median,
+    InlineMed,
+    MedianAbsoluteDeviation,
+    InlineMedAd,
     BollingerBands,
     ChandelierExit,
     EfficiencyRatio,
     FastStochastic,
+    FullStochastic,
+    SmoothedMovingAverage,
+    VolumeWeightedMovingAverage,
+    HullMovingAverage,
+    ZeroLagExponentialMovingAverage,
+    ArnaudLegouxMovingAverage,
+    T3MovingAverage,
+    McGinleyDynamic,
+    VariableIndexDynamicAverage,
+    FractalAdaptiveMovingAverage,
     KeltnerChannel,
+    DonchianChannel,
     Maximum,
+    InlineMax,
     Minimum,
+    InlineMin,
     MoneyFlowIndex,
     MovingAverageConvergenceDivergence,
+    NormalizedAverageTrueRange,
     OnBalanceVolume,
     PercentagePriceOscillator,
     CommodityChannelIndex,
     RateOfChange,
+    RateOfChangePercentage,
+    RateOfChangeRatio,
+    RateOfChangeRatio100,
+    Momentum,
+    LogReturn,
     RelativeStrengthIndex,
     SimpleMovingAverage,
+    InlineSma,
     SlowStochastic,
+    Squeeze,
     StandardDeviation,
+    InlineSd,
+    StochasticRsi,
+    Supertrend,
     TrueRange,
-    WeightedMovingAverage
+    Variance,
+    InlineVar,
+    WeightedMovingAverage,
+    InlineWma,
+    WilliamsR
 );
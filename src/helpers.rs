@@ -1,8 +1,53 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Returns the largest of 3 given numbers.
 pub fn max3(a: f64, b: f64, c: f64) -> f64 {
     a.max(b).max(c)
 }
 
+/// Running sum with Neumaier compensation.
+///
+/// The rounding error of every addition is accumulated separately, so subtracting large
+/// values again does not leave their rounding errors behind in a small sum.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Default)]
+pub struct CompensatedSum {
+    sum: f64,
+    compensation: f64,
+}
+
+impl CompensatedSum {
+    pub fn add(&mut self, value: f64) {
+        let sum = self.sum + value;
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - sum) + value;
+        } else {
+            self.compensation += (value - sum) + self.sum;
+        }
+        self.sum = sum;
+    }
+
+    pub fn value(&self) -> f64 {
+        self.sum + self.compensation
+    }
+
+    pub fn clear(&mut self) {
+        self.sum = 0.0;
+        self.compensation = 0.0;
+    }
+}
+
+impl FromIterator<f64> for CompensatedSum {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let mut sum = Self::default();
+        for value in iter {
+            sum.add(value);
+        }
+        sum
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -13,4 +58,16 @@ mod tests {
         assert_eq!(max3(2.0, 3.0, 1.0), 3.0);
         assert_eq!(max3(2.0, 1.0, 3.0), 3.0);
     }
+
+    #[test]
+    fn test_compensated_sum() {
+        let mut sum: CompensatedSum = [1e16, 1.0, -1e16].into_iter().collect();
+        assert_eq!(sum.value(), 1.0);
+
+        sum.add(0.5);
+        assert_eq!(sum.value(), 1.5);
+
+        sum.clear();
+        assert_eq!(sum.value(), 0.0);
+    }
 }
@@ -0,0 +1,175 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::Maximum;
+use crate::{High, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Returns the highest value in a given time frame together with its age and position.
+///
+/// It is a [Maximum] which additionally reports how many bars ago the highest value
+/// occurred, which is needed for indicators like Aroon or to measure the age of a breakout.
+///
+/// # Output
+///
+/// * _value_ - the highest value in the time frame
+/// * _age_ - number of bars since the highest value, `0` means the current bar.
+///   When the highest value occurs several times, the most recent one is taken.
+/// * _index_ - position of the highest value, counted from the first input
+///
+/// # Parameters
+///
+/// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ArgMaximum;
+/// use ta::Next;
+///
+/// let mut argmax = ArgMaximum::new(3).unwrap();
+/// let output = argmax.next(4.0);
+/// assert_eq!((output.value, output.age, output.index), (4.0, 0, 0));
+/// let output = argmax.next(1.2);
+/// assert_eq!((output.value, output.age, output.index), (4.0, 1, 0));
+/// let output = argmax.next(5.0);
+/// assert_eq!((output.value, output.age, output.index), (5.0, 0, 2));
+/// let output = argmax.next(3.0);
+/// assert_eq!((output.value, output.age, output.index), (5.0, 1, 2));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ArgMaximum {
+    maximum: Maximum,
+}
+
+impl ArgMaximum {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            maximum: Maximum::new(period)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArgMaximumOutput {
+    pub value: f64,
+    pub age: usize,
+    pub index: usize,
+}
+
+impl From<ArgMaximumOutput> for (f64, usize, usize) {
+    fn from(output: ArgMaximumOutput) -> Self {
+        (output.value, output.age, output.index)
+    }
+}
+
+impl Period for ArgMaximum {
+    fn period(&self) -> usize {
+        self.maximum.period()
+    }
+}
+
+impl Next<f64> for ArgMaximum {
+    type Output = ArgMaximumOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let value = self.maximum.next(input);
+
+        Self::Output {
+            value,
+            age: self.maximum.age(),
+            index: self.maximum.index(),
+        }
+    }
+}
+
+impl<T: High> Next<&T> for ArgMaximum {
+    type Output = ArgMaximumOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.high())
+    }
+}
+
+impl Reset for ArgMaximum {
+    fn reset(&mut self) {
+        self.maximum.reset();
+    }
+}
+
+impl Default for ArgMaximum {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for ArgMaximum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ARGMAX({})", self.maximum.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn tuple(output: ArgMaximumOutput) -> (f64, usize, usize) {
+        output.into()
+    }
+
+    test_indicator!(ArgMaximum);
+
+    #[test]
+    fn test_new() {
+        assert!(ArgMaximum::new(0).is_err());
+        assert!(ArgMaximum::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut argmax = ArgMaximum::new(3).unwrap();
+
+        assert_eq!(tuple(argmax.next(4.0)), (4.0, 0, 0));
+        assert_eq!(tuple(argmax.next(1.2)), (4.0, 1, 0));
+        assert_eq!(tuple(argmax.next(5.0)), (5.0, 0, 2));
+        assert_eq!(tuple(argmax.next(3.0)), (5.0, 1, 2));
+        assert_eq!(tuple(argmax.next(4.0)), (5.0, 2, 2));
+        assert_eq!(tuple(argmax.next(0.0)), (4.0, 1, 4));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64) -> Bar {
+            Bar::new().high(high)
+        }
+
+        let mut argmax = ArgMaximum::new(2).unwrap();
+
+        assert_eq!(tuple(argmax.next(&bar(2.0))), (2.0, 0, 0));
+        assert_eq!(argmax.next(&bar(3.0)).value, 3.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut argmax = ArgMaximum::new(3).unwrap();
+        argmax.next(5.0);
+        argmax.next(6.0);
+
+        argmax.reset();
+        assert_eq!(tuple(argmax.next(1.0)), (1.0, 0, 0));
+    }
+
+    #[test]
+    fn test_default() {
+        ArgMaximum::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ArgMaximum::new(7).unwrap();
+        assert_eq!(format!("{}", indicator), "ARGMAX(7)");
+    }
+}
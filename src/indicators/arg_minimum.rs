@@ -0,0 +1,175 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::Minimum;
+use crate::{Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Returns the lowest value in a given time frame together with its age and position.
+///
+/// It is a [Minimum] which additionally reports how many bars ago the lowest value
+/// occurred, which is needed for indicators like Aroon or to measure the age of a breakout.
+///
+/// # Output
+///
+/// * _value_ - the lowest value in the time frame
+/// * _age_ - number of bars since the lowest value, `0` means the current bar.
+///   When the lowest value occurs several times, the most recent one is taken.
+/// * _index_ - position of the lowest value, counted from the first input
+///
+/// # Parameters
+///
+/// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ArgMinimum;
+/// use ta::Next;
+///
+/// let mut argmin = ArgMinimum::new(3).unwrap();
+/// let output = argmin.next(4.0);
+/// assert_eq!((output.value, output.age, output.index), (4.0, 0, 0));
+/// let output = argmin.next(5.0);
+/// assert_eq!((output.value, output.age, output.index), (4.0, 1, 0));
+/// let output = argmin.next(1.2);
+/// assert_eq!((output.value, output.age, output.index), (1.2, 0, 2));
+/// let output = argmin.next(3.0);
+/// assert_eq!((output.value, output.age, output.index), (1.2, 1, 2));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ArgMinimum {
+    minimum: Minimum,
+}
+
+impl ArgMinimum {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            minimum: Minimum::new(period)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArgMinimumOutput {
+    pub value: f64,
+    pub age: usize,
+    pub index: usize,
+}
+
+impl From<ArgMinimumOutput> for (f64, usize, usize) {
+    fn from(output: ArgMinimumOutput) -> Self {
+        (output.value, output.age, output.index)
+    }
+}
+
+impl Period for ArgMinimum {
+    fn period(&self) -> usize {
+        self.minimum.period()
+    }
+}
+
+impl Next<f64> for ArgMinimum {
+    type Output = ArgMinimumOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let value = self.minimum.next(input);
+
+        Self::Output {
+            value,
+            age: self.minimum.age(),
+            index: self.minimum.index(),
+        }
+    }
+}
+
+impl<T: Low> Next<&T> for ArgMinimum {
+    type Output = ArgMinimumOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.low())
+    }
+}
+
+impl Reset for ArgMinimum {
+    fn reset(&mut self) {
+        self.minimum.reset();
+    }
+}
+
+impl Default for ArgMinimum {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for ArgMinimum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ARGMIN({})", self.minimum.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn tuple(output: ArgMinimumOutput) -> (f64, usize, usize) {
+        output.into()
+    }
+
+    test_indicator!(ArgMinimum);
+
+    #[test]
+    fn test_new() {
+        assert!(ArgMinimum::new(0).is_err());
+        assert!(ArgMinimum::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut argmin = ArgMinimum::new(3).unwrap();
+
+        assert_eq!(tuple(argmin.next(4.0)), (4.0, 0, 0));
+        assert_eq!(tuple(argmin.next(5.0)), (4.0, 1, 0));
+        assert_eq!(tuple(argmin.next(1.2)), (1.2, 0, 2));
+        assert_eq!(tuple(argmin.next(3.0)), (1.2, 1, 2));
+        assert_eq!(tuple(argmin.next(4.0)), (1.2, 2, 2));
+        assert_eq!(tuple(argmin.next(6.0)), (3.0, 2, 3));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(low: f64) -> Bar {
+            Bar::new().low(low)
+        }
+
+        let mut argmin = ArgMinimum::new(2).unwrap();
+
+        assert_eq!(tuple(argmin.next(&bar(2.0))), (2.0, 0, 0));
+        assert_eq!(argmin.next(&bar(3.0)).value, 2.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut argmin = ArgMinimum::new(3).unwrap();
+        argmin.next(5.0);
+        argmin.next(6.0);
+
+        argmin.reset();
+        assert_eq!(tuple(argmin.next(1.0)), (1.0, 0, 0));
+    }
+
+    #[test]
+    fn test_default() {
+        ArgMinimum::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = ArgMinimum::new(7).unwrap();
+        assert_eq!(format!("{}", indicator), "ARGMIN(7)");
+    }
+}
@@ -0,0 +1,246 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset, RollingWindow};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Arnaud Legoux moving average (ALMA).
+///
+/// Developed by Arnaud Legoux and Dimitrios Kouzis-Loukas, ALMA weights the values of the
+/// window with a Gaussian curve. Its center, set by _offset_, balances smoothness
+/// (towards the oldest value) and responsiveness (towards the newest value),
+/// while _sigma_ sets its width.
+///
+/// # Formula
+///
+/// ALMA<sub>t</sub> = Σ w<sub>i</sub> * p<sub>t-period+1+i</sub> / Σ w<sub>i</sub>, i = 0.._period_ - 1
+///
+/// Where:
+///
+/// * _w<sub>i</sub>_ = exp(-(i - m)<sup>2</sup> / (2 * s<sup>2</sup>))
+/// * _m_ = _offset_ * (_period_ - 1)
+/// * _s_ = _period_ / _sigma_
+///
+/// Until the window is full, the available values get the weights of the newest positions.
+/// While all of these weights are 0, the input is returned as is.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+/// * _offset_ - position of the center of the weights, from 0 (oldest) to 1 (newest). Default is 0.85.
+/// * _sigma_ - sharpness of the weights (finite, greater than 0 and small enough for the
+///   weights not to be all 0). Default is 6.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ArnaudLegouxMovingAverage;
+/// use ta::Next;
+///
+/// // Weights centered on the middle of the window
+/// let mut alma = ArnaudLegouxMovingAverage::with_params(3, 0.5, 6.0).unwrap();
+/// assert_eq!(alma.next(10.0), 10.0);
+/// alma.next(20.0);
+/// assert_eq!(alma.next(30.0).round(), 20.0);
+/// ```
+///
+/// # Links
+///
+/// * [Arnaud Legoux Moving Average, TradingView](https://www.tradingview.com/support/solutions/43000594683-arnaud-legoux-moving-average/)
+///
+#[doc(alias = "ALMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ArnaudLegouxMovingAverage {
+    offset: f64,
+    sigma: f64,
+    weights: Box<[f64]>,
+    window: RollingWindow,
+}
+
+impl ArnaudLegouxMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_params(period, 0.85, 6.0)
+    }
+
+    pub fn with_params(period: usize, offset: f64, sigma: f64) -> Result<Self> {
+        if !(0.0..=1.0).contains(&offset) || !sigma.is_finite() || sigma <= 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let m = offset * (period.saturating_sub(1)) as f64;
+        let s = period as f64 / sigma;
+        let weights: Box<[f64]> = (0..period)
+            .map(|i| (-(i as f64 - m).powi(2) / (2.0 * s * s)).exp())
+            .collect();
+
+        // A narrow curve between two positions underflows all weights
+        if weights.iter().sum::<f64>() == 0.0 {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            offset,
+            sigma,
+            weights,
+            window: RollingWindow::new(period)?,
+        })
+    }
+
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+}
+
+impl Period for ArnaudLegouxMovingAverage {
+    fn period(&self) -> usize {
+        self.window.capacity()
+    }
+}
+
+impl Next<f64> for ArnaudLegouxMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.window.push(input);
+
+        let weights = &self.weights[self.weights.len() - self.window.len()..];
+        let (sum, norm) = self
+            .window
+            .iter()
+            .zip(weights.iter())
+            .fold((0.0, 0.0), |(sum, norm), (value, weight)| {
+                (sum + value * weight, norm + weight)
+            });
+
+        if norm == 0.0 {
+            return input;
+        }
+        sum / norm
+    }
+}
+
+impl<T: Close> Next<&T> for ArnaudLegouxMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ArnaudLegouxMovingAverage {
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+impl Default for ArnaudLegouxMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for ArnaudLegouxMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "ALMA({}, {}, {})",
+            self.period(),
+            self.offset,
+            self.sigma
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(ArnaudLegouxMovingAverage);
+
+    const INPUTS: [f64; 8] = [10.0, 13.0, 16.0, 14.0, 12.0, 15.0, 18.0, 17.0];
+
+    #[test]
+    fn test_new() {
+        assert!(ArnaudLegouxMovingAverage::new(0).is_err());
+        assert!(ArnaudLegouxMovingAverage::new(1).is_ok());
+        assert!(ArnaudLegouxMovingAverage::with_params(3, -0.1, 6.0).is_err());
+        assert!(ArnaudLegouxMovingAverage::with_params(3, 1.1, 6.0).is_err());
+        assert!(ArnaudLegouxMovingAverage::with_params(3, f64::NAN, 6.0).is_err());
+        assert!(ArnaudLegouxMovingAverage::with_params(3, 0.85, 0.0).is_err());
+        assert!(ArnaudLegouxMovingAverage::with_params(3, 0.85, f64::NAN).is_err());
+        assert!(ArnaudLegouxMovingAverage::with_params(3, 0.85, f64::INFINITY).is_err());
+        assert!(ArnaudLegouxMovingAverage::with_params(9, 0.85, 1e6).is_err());
+        assert!(ArnaudLegouxMovingAverage::with_params(3, 0.0, 1.0).is_ok());
+        assert!(ArnaudLegouxMovingAverage::with_params(3, 1.0, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut alma = ArnaudLegouxMovingAverage::new(3).unwrap();
+        assert_eq!((alma.offset(), alma.sigma()), (0.85, 6.0));
+
+        let expected = [10.0, 12.07, 15.057, 14.616, 12.629, 14.07, 17.057, 17.304];
+        for (input, expected) in INPUTS.iter().zip(expected.iter()) {
+            assert_eq!(round(alma.next(*input)), *expected);
+        }
+    }
+
+    #[test]
+    fn test_next_with_params() {
+        let mut alma = ArnaudLegouxMovingAverage::with_params(4, 0.5, 2.0).unwrap();
+
+        let expected = [10.0, 11.313, 12.761, 13.405, 13.905, 14.095, 14.595, 15.624];
+        for (input, expected) in INPUTS.iter().zip(expected.iter()) {
+            assert_eq!(round(alma.next(*input)), *expected);
+        }
+    }
+
+    #[test]
+    fn test_next_underflow() {
+        // Only the oldest positions have a weight greater than 0
+        let mut alma = ArnaudLegouxMovingAverage::with_params(50, 0.0, 100.0).unwrap();
+
+        assert_eq!(alma.next(10.0), 10.0);
+        assert_eq!(alma.next(13.0), 13.0);
+        for input in INPUTS.iter().cycle().take(100) {
+            assert!(alma.next(*input).is_finite());
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut alma = ArnaudLegouxMovingAverage::new(3).unwrap();
+
+        assert_eq!(round(alma.next(&Bar::new().close(10))), 10.0);
+        assert_eq!(round(alma.next(&Bar::new().close(13))), 12.07);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut alma = ArnaudLegouxMovingAverage::new(3).unwrap();
+
+        assert_eq!(round(alma.next(10.0)), 10.0);
+        assert_eq!(round(alma.next(13.0)), 12.07);
+
+        alma.reset();
+        assert_eq!(round(alma.next(13.0)), 13.0);
+    }
+
+    #[test]
+    fn test_default() {
+        ArnaudLegouxMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let alma = ArnaudLegouxMovingAverage::new(9).unwrap();
+        assert_eq!(format!("{}", alma), "ALMA(9, 0.85, 6)");
+    }
+}
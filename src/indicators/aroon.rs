@@ -0,0 +1,208 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::{Maximum, Minimum};
+use crate::{High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Aroon indicator.
+///
+/// Developed by Tushar Chande, Aroon measures the time elapsed since the highest high
+/// (Aroon Up) and the lowest low (Aroon Down) of the last _period_ + 1 bars.
+/// A recent high gives an Aroon Up close to 100 and signals an uptrend,
+/// a recent low gives an Aroon Down close to 100 and signals a downtrend.
+/// The Aroon Oscillator, their difference, ranges between -100 and 100.
+///
+/// # Formula
+///
+/// * _Aroon Up_ = (_period_ - bars since the highest high) / _period_ * 100
+/// * _Aroon Down_ = (_period_ - bars since the lowest low) / _period_ * 100
+/// * _Aroon Oscillator_ = _Aroon Up_ - _Aroon Down_
+///
+/// The extremes are searched in the current bar and the _period_ previous bars.
+/// When an extreme occurs several times, the most recent one is taken.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 25.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Aroon;
+/// use ta::Next;
+///
+/// let mut aroon = Aroon::new(4).unwrap();
+/// aroon.next(3.0);
+/// aroon.next(4.0);
+/// let out = aroon.next(2.0);
+/// assert_eq!(out.up, 75.0);
+/// assert_eq!(out.down, 100.0);
+/// assert_eq!(out.oscillator, -25.0);
+/// ```
+///
+/// # Links
+///
+/// * [Aroon, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:aroon)
+/// * [Aroon Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:aroon_oscillator)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Aroon {
+    period: usize,
+    max: Maximum,
+    min: Minimum,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AroonOutput {
+    pub up: f64,
+    pub down: f64,
+    pub oscillator: f64,
+}
+
+impl From<AroonOutput> for (f64, f64, f64) {
+    fn from(aroon: AroonOutput) -> Self {
+        (aroon.up, aroon.down, aroon.oscillator)
+    }
+}
+
+impl Aroon {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                max: Maximum::new(period + 1)?,
+                min: Minimum::new(period + 1)?,
+            }),
+        }
+    }
+
+    fn output(&self) -> AroonOutput {
+        let period = self.period as f64;
+        let up = (period - self.max.age() as f64) / period * 100.0;
+        let down = (period - self.min.age() as f64) / period * 100.0;
+
+        AroonOutput {
+            up,
+            down,
+            oscillator: up - down,
+        }
+    }
+}
+
+impl Period for Aroon {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for Aroon {
+    type Output = AroonOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.max.next(input);
+        self.min.next(input);
+        self.output()
+    }
+}
+
+impl<T: High + Low> Next<&T> for Aroon {
+    type Output = AroonOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.max.next(input.high());
+        self.min.next(input.low());
+        self.output()
+    }
+}
+
+impl Reset for Aroon {
+    fn reset(&mut self) {
+        self.max.reset();
+        self.min.reset();
+    }
+}
+
+impl Default for Aroon {
+    fn default() -> Self {
+        Self::new(25).unwrap()
+    }
+}
+
+impl fmt::Display for Aroon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AROON({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Aroon);
+
+    fn tuple(output: AroonOutput) -> (f64, f64, f64) {
+        let (up, down, oscillator) = output.into();
+        (round(up), round(down), round(oscillator))
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Aroon::new(0).is_err());
+        assert!(Aroon::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_with_f64() {
+        let mut aroon = Aroon::new(3).unwrap();
+
+        assert_eq!(tuple(aroon.next(1.0)), (100.0, 100.0, 0.0));
+        assert_eq!(tuple(aroon.next(2.0)), (100.0, 66.667, 33.333));
+        assert_eq!(tuple(aroon.next(3.0)), (100.0, 33.333, 66.667));
+        assert_eq!(tuple(aroon.next(2.0)), (66.667, 0.0, 66.667));
+        assert_eq!(tuple(aroon.next(1.0)), (33.333, 100.0, -66.667));
+        assert_eq!(tuple(aroon.next(0.0)), (0.0, 100.0, -100.0));
+        assert_eq!(tuple(aroon.next(1.0)), (0.0, 66.667, -66.667));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64, low: f64) -> Bar {
+            Bar::new().high(high).low(low)
+        }
+
+        let mut aroon = Aroon::new(2).unwrap();
+
+        assert_eq!(tuple(aroon.next(&bar(10.0, 8.0))), (100.0, 100.0, 0.0));
+        assert_eq!(tuple(aroon.next(&bar(12.0, 9.0))), (100.0, 50.0, 50.0));
+        assert_eq!(tuple(aroon.next(&bar(11.0, 7.0))), (50.0, 100.0, -50.0));
+        assert_eq!(tuple(aroon.next(&bar(9.0, 8.0))), (0.0, 50.0, -50.0));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut aroon = Aroon::new(3).unwrap();
+
+        assert_eq!(tuple(aroon.next(1.0)), (100.0, 100.0, 0.0));
+        assert_eq!(tuple(aroon.next(2.0)), (100.0, 66.667, 33.333));
+
+        aroon.reset();
+        assert_eq!(tuple(aroon.next(2.0)), (100.0, 100.0, 0.0));
+        assert_eq!(tuple(aroon.next(1.0)), (66.667, 100.0, -33.333));
+    }
+
+    #[test]
+    fn test_default() {
+        Aroon::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Aroon::new(14).unwrap();
+        assert_eq!(format!("{}", indicator), "AROON(14)");
+    }
+}
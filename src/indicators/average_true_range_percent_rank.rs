@@ -0,0 +1,189 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::AverageTrueRange;
+use crate::{Close, High, Low, Next, Period, Reset, RollingWindow};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Percent rank of the average true range.
+///
+/// Ranks the current [average true range](struct.AverageTrueRange.html) against its own
+/// history: the percentage of the previous _rank_period_ ATR values that are less than
+/// or equal to the current one. The result ranges from 0 (lowest volatility of the
+/// lookback window) to 100 (highest volatility of the lookback window), regardless of
+/// the price level of the instrument.
+///
+/// # Formula
+///
+/// ATR_RANK<sub>t</sub> = count(ATR<sub>t-i</sub> <= ATR<sub>t</sub>, i = 1.._rank_period_) / _n_ * 100
+///
+/// Where:
+///
+/// * _ATR<sub>t</sub>_ - [average true range](struct.AverageTrueRange.html) with _period_
+/// * _n_ - number of previous ATR values, up to _rank_period_
+///
+/// Until there is a previous value, the rank is 50.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period of ATR (integer greater than 0). Default is 14.
+/// * _rank_period_ - number of previous ATR values to rank against (integer greater than 0). Default is 100.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::AverageTrueRangePercentRank;
+/// use ta::Next;
+///
+/// let mut rank = AverageTrueRangePercentRank::new(1, 3).unwrap();
+///
+/// assert_eq!(rank.next(10.0), 50.0); // ATR = 0.0
+/// assert_eq!(rank.next(12.0), 100.0); // ATR = 2.0
+/// assert_eq!(rank.next(11.0), 50.0); // ATR = 1.0
+/// ```
+///
+/// # Links
+///
+/// * [Percentile rank, Wikipedia](https://en.wikipedia.org/wiki/Percentile_rank)
+///
+#[doc(alias = "ATR_RANK")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct AverageTrueRangePercentRank {
+    atr: AverageTrueRange,
+    window: RollingWindow,
+}
+
+impl AverageTrueRangePercentRank {
+    pub fn new(period: usize, rank_period: usize) -> Result<Self> {
+        Ok(Self {
+            atr: AverageTrueRange::new(period)?,
+            window: RollingWindow::new(rank_period)?,
+        })
+    }
+
+    pub fn rank_period(&self) -> usize {
+        self.window.capacity()
+    }
+
+    fn rank(&mut self, atr: f64) -> f64 {
+        let rank = if self.window.is_empty() {
+            50.0
+        } else {
+            let below = self.window.iter().filter(|&value| value <= atr).count();
+            below as f64 / self.window.len() as f64 * 100.0
+        };
+        self.window.push(atr);
+        rank
+    }
+}
+
+impl Period for AverageTrueRangePercentRank {
+    fn period(&self) -> usize {
+        self.atr.period()
+    }
+}
+
+impl Next<f64> for AverageTrueRangePercentRank {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let atr = self.atr.next(input);
+        self.rank(atr)
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for AverageTrueRangePercentRank {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let atr = self.atr.next(input);
+        self.rank(atr)
+    }
+}
+
+impl Reset for AverageTrueRangePercentRank {
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.window.clear();
+    }
+}
+
+impl Default for AverageTrueRangePercentRank {
+    fn default() -> Self {
+        Self::new(14, 100).unwrap()
+    }
+}
+
+impl fmt::Display for AverageTrueRangePercentRank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ATR_RANK({}, {})", self.period(), self.rank_period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(AverageTrueRangePercentRank);
+
+    #[test]
+    fn test_new() {
+        assert!(AverageTrueRangePercentRank::new(0, 1).is_err());
+        assert!(AverageTrueRangePercentRank::new(1, 0).is_err());
+        assert!(AverageTrueRangePercentRank::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut rank = AverageTrueRangePercentRank::new(1, 3).unwrap();
+
+        // With a period of 1, ATR is the true range: 0, 2, 1, 4, 0, 1
+        assert_eq!(rank.next(10.0), 50.0);
+        assert_eq!(rank.next(12.0), 100.0);
+        assert_eq!(rank.next(11.0), 50.0);
+        assert_eq!(rank.next(15.0), 100.0);
+        assert_eq!(rank.next(15.0), 0.0);
+        assert_eq!(round(rank.next(14.0)), 66.667);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut rank = AverageTrueRangePercentRank::new(3, 2).unwrap();
+
+        let bar1 = Bar::new().high(10).low(7.5).close(9);
+        let bar2 = Bar::new().high(11).low(9).close(9.5);
+        let bar3 = Bar::new().high(9).low(5).close(8);
+
+        // ATR: 2.5, 2.25, 3.375
+        assert_eq!(rank.next(&bar1), 50.0);
+        assert_eq!(rank.next(&bar2), 0.0);
+        assert_eq!(rank.next(&bar3), 100.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut rank = AverageTrueRangePercentRank::new(1, 3).unwrap();
+
+        assert_eq!(rank.next(10.0), 50.0);
+        assert_eq!(rank.next(12.0), 100.0);
+
+        rank.reset();
+        assert_eq!(rank.next(12.0), 50.0);
+        assert_eq!(rank.next(12.0), 100.0);
+    }
+
+    #[test]
+    fn test_default() {
+        AverageTrueRangePercentRank::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = AverageTrueRangePercentRank::new(14, 50).unwrap();
+        assert_eq!(format!("{}", indicator), "ATR_RANK(14, 50)");
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::errors::Result;
-use crate::indicators::StandardDeviation as Sd;
+use crate::indicators::{StandardDeviation as Sd, VarianceEstimator};
 use crate::{Close, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -21,6 +21,14 @@ use serde::{Deserialize, Serialize};
 ///  * _BB<sub>Upper Band</sub>_ = SMA + SD of observation * multipler (usually 2.0)
 ///  * _BB<sub>Lower Band</sub>_ = SMA - SD of observation * multipler (usually 2.0)
 ///
+/// Along with the bands the output contains:
+///
+///  * _%B_ = (price - lower) / (upper - lower), it is 0.5 when the bands are collapsed
+///  * _Bandwidth_ = (upper - lower) / SMA, it is 0.0 when SMA is 0.0
+///
+/// The standard deviation uses the population estimator, unless another one is given
+/// with [BollingerBands::with_estimator].
+///
 /// # Example
 ///
 ///```
@@ -40,6 +48,7 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(out_1.average, 3.5);
 /// assert_eq!(out_1.upper, 6.5);
 /// assert_eq!(out_1.lower, 0.5);
+/// assert_eq!(out_1.percent_b, 0.75);
 /// ```
 ///
 /// # Links
@@ -59,20 +68,34 @@ pub struct BollingerBandsOutput {
     pub average: f64,
     pub upper: f64,
     pub lower: f64,
+    pub percent_b: f64,
+    pub bandwidth: f64,
 }
 
 impl BollingerBands {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Self::with_estimator(period, multiplier, VarianceEstimator::Population)
+    }
+
+    pub fn with_estimator(
+        period: usize,
+        multiplier: f64,
+        estimator: VarianceEstimator,
+    ) -> Result<Self> {
         Ok(Self {
             period,
             multiplier,
-            sd: Sd::new(period)?,
+            sd: Sd::with_estimator(period, estimator)?,
         })
     }
 
     pub fn multiplier(&self) -> f64 {
         self.multiplier
     }
+
+    pub fn estimator(&self) -> VarianceEstimator {
+        self.sd.estimator()
+    }
 }
 
 impl Period for BollingerBands {
@@ -87,11 +110,23 @@ impl Next<f64> for BollingerBands {
     fn next(&mut self, input: f64) -> Self::Output {
         let sd = self.sd.next(input);
         let mean = self.sd.mean();
+        let upper = mean + sd * self.multiplier;
+        let lower = mean - sd * self.multiplier;
+
+        let width = upper - lower;
+        let percent_b = if width == 0.0 {
+            0.5
+        } else {
+            (input - lower) / width
+        };
+        let bandwidth = if mean == 0.0 { 0.0 } else { width / mean };
 
         Self::Output {
             average: mean,
-            upper: mean + sd * self.multiplier,
-            lower: mean - sd * self.multiplier,
+            upper,
+            lower,
+            percent_b,
+            bandwidth,
         }
     }
 }
@@ -159,6 +194,43 @@ mod tests {
         assert_eq!(round(b.lower), 0.5);
         assert_eq!(round(c.lower), -0.733);
         assert_eq!(round(d.lower), -0.395);
+
+        assert_eq!(a.percent_b, 0.5);
+        assert_eq!(round(b.percent_b), 0.75);
+        assert_eq!(round(c.percent_b), 0.255);
+        assert_eq!(round(d.percent_b), 0.742);
+
+        assert_eq!(a.bandwidth, 0.0);
+        assert_eq!(round(b.bandwidth), 1.714);
+        assert_eq!(round(c.bandwidth), 2.55);
+        assert_eq!(round(d.bandwidth), 2.193);
+    }
+
+    #[test]
+    fn test_next_sample() {
+        let mut bb = BollingerBands::with_estimator(3, 2.0_f64, VarianceEstimator::Sample).unwrap();
+        assert_eq!(bb.estimator(), VarianceEstimator::Sample);
+
+        let a = bb.next(2.0);
+        let b = bb.next(5.0);
+        let c = bb.next(1.0);
+
+        assert_eq!((a.average, a.upper, a.lower), (2.0, 2.0, 2.0));
+        assert_eq!(round(b.upper), 7.743);
+        assert_eq!(round(b.lower), -0.743);
+        assert_eq!(round(c.upper), 6.83);
+        assert_eq!(round(c.lower), -1.497);
+    }
+
+    #[test]
+    fn test_next_zero_average() {
+        let mut bb = BollingerBands::new(2, 2.0_f64).unwrap();
+
+        bb.next(-1.0);
+        let out = bb.next(1.0);
+        assert_eq!(out.average, 0.0);
+        assert_eq!(out.bandwidth, 0.0);
+        assert_eq!(out.percent_b, 0.75);
     }
 
     #[test]
@@ -4,7 +4,7 @@ use std::fmt;
 use serde::{Deserialize, Serialize};
 
 use crate::errors::Result;
-use crate::indicators::{AverageTrueRange, Maximum, Minimum};
+use crate::indicators::{AverageTrueRange, Direction, Maximum, Minimum};
 use crate::{Close, High, Low, Next, Period, Reset};
 
 /// Chandelier Exit (CE).
@@ -16,13 +16,26 @@ use crate::{Close, High, Low, Next, Period, Reset};
 ///
 /// # Formula
 ///
-/// Chandelier Exit (long) = Max(_period_) - ATR(_period_) * _multipler_
-/// Chandelier Exit (short) = Min(_period_) + ATR(_period_) * _multipler_
+/// Chandelier Exit (long) = Max(_lookback_period_) - ATR(_period_) * _multipler_
+/// Chandelier Exit (short) = Min(_lookback_period_) + ATR(_period_) * _multipler_
+///
+/// The extremes are the highest high and the lowest low, or the highest and
+/// the lowest close with [ChandelierExitExtremes::Close].
+///
+/// The direction turns [Short](Direction::Short) when the close falls below the previous
+/// long stop and [Long](Direction::Long) when it rises above the previous short stop.
+/// In ratcheting mode the stop of the current direction never loosens: the long stop never
+/// decreases while the direction is long and the short stop never increases while it is short.
+/// The other stop follows the extremes, so it starts from them again when the direction flips.
 ///
 /// # Parameters
 ///
-/// * _period_ - number of periods (integer greater than 0). Default is 22.
+/// * _period_ - number of periods of ATR (integer greater than 0). Default is 22.
+/// * _lookback_period_ - number of periods of the extremes (integer greater than 0).
+///   Default is the same as _period_.
 /// * _multipler_ - ATR factor. Default is 3.
+/// * _extremes_ - see [ChandelierExitExtremes]. Default is high and low.
+/// * _ratchet_ - enables ratcheting mode. Default is false.
 ///
 /// # Example
 ///
@@ -58,27 +71,75 @@ pub struct ChandelierExit {
     min: Minimum,
     max: Maximum,
     multiplier: f64,
+    extremes: ChandelierExitExtremes,
+    ratchet: bool,
+    direction: Direction,
+    // Long stop and short stop of the previous bar
+    previous: Option<(f64, f64)>,
+}
+
+/// Prices used for the extremes of the [ChandelierExit].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChandelierExitExtremes {
+    /// Highest high and lowest low.
+    HighLow,
+    /// Highest close and lowest close.
+    Close,
 }
 
 impl ChandelierExit {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Self::with_params(
+            period,
+            period,
+            multiplier,
+            ChandelierExitExtremes::HighLow,
+            false,
+        )
+    }
+
+    pub fn with_params(
+        period: usize,
+        lookback_period: usize,
+        multiplier: f64,
+        extremes: ChandelierExitExtremes,
+        ratchet: bool,
+    ) -> Result<Self> {
         Ok(Self {
             atr: AverageTrueRange::new(period)?,
-            min: Minimum::new(period)?,
-            max: Maximum::new(period)?,
+            min: Minimum::new(lookback_period)?,
+            max: Maximum::new(lookback_period)?,
             multiplier,
+            extremes,
+            ratchet,
+            direction: Direction::Long,
+            previous: None,
         })
     }
 
     pub fn multiplier(&self) -> f64 {
         self.multiplier
     }
+
+    pub fn lookback_period(&self) -> usize {
+        self.max.period()
+    }
+
+    pub fn extremes(&self) -> ChandelierExitExtremes {
+        self.extremes
+    }
+
+    pub fn is_ratchet(&self) -> bool {
+        self.ratchet
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ChandelierExitOutput {
     pub long: f64,
     pub short: f64,
+    pub direction: Direction,
 }
 
 impl From<ChandelierExitOutput> for (f64, f64) {
@@ -98,12 +159,40 @@ impl<T: Low + High + Close> Next<&T> for ChandelierExit {
 
     fn next(&mut self, input: &T) -> Self::Output {
         let atr = self.atr.next(input) * self.multiplier;
-        let min = self.min.next(input);
-        let max = self.max.next(input);
+        let (max, min) = match self.extremes {
+            ChandelierExitExtremes::HighLow => (self.max.next(input), self.min.next(input)),
+            ChandelierExitExtremes::Close => {
+                (self.max.next(input.close()), self.min.next(input.close()))
+            }
+        };
+
+        let close = input.close();
+        let mut long = max - atr;
+        let mut short = min + atr;
+
+        if let Some((prev_long, prev_short)) = self.previous {
+            let direction = if close > prev_short {
+                Direction::Long
+            } else if close < prev_long {
+                Direction::Short
+            } else {
+                self.direction
+            };
+
+            if self.ratchet && direction == self.direction {
+                match direction {
+                    Direction::Long => long = long.max(prev_long),
+                    Direction::Short => short = short.min(prev_short),
+                }
+            }
+            self.direction = direction;
+        }
+        self.previous = Some((long, short));
 
         ChandelierExitOutput {
-            long: max - atr,
-            short: min + atr,
+            long,
+            short,
+            direction: self.direction,
         }
     }
 }
@@ -113,6 +202,8 @@ impl Reset for ChandelierExit {
         self.atr.reset();
         self.min.reset();
         self.max.reset();
+        self.direction = Direction::Long;
+        self.previous = None;
     }
 }
 
@@ -124,7 +215,17 @@ impl Default for ChandelierExit {
 
 impl fmt::Display for ChandelierExit {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "CE({}, {})", self.atr.period(), self.multiplier)
+        if self.period() == self.lookback_period() {
+            write!(f, "CE({}, {})", self.period(), self.multiplier)
+        } else {
+            write!(
+                f,
+                "CE({}, {}, {})",
+                self.period(),
+                self.lookback_period(),
+                self.multiplier
+            )
+        }
     }
 }
 
@@ -147,6 +248,7 @@ mod tests {
         assert!(Ce::new(0, 0.0).is_err());
         assert!(Ce::new(1, 1.0).is_ok());
         assert!(Ce::new(22, 3.0).is_ok());
+        assert!(Ce::with_params(22, 0, 3.0, ChandelierExitExtremes::Close, true).is_err());
     }
 
     #[test]
@@ -172,6 +274,107 @@ mod tests {
         assert_eq!(round(ce.next(&bar6).into()), (2.92, 7.08));
     }
 
+    fn triple(output: ChandelierExitOutput) -> (f64, f64, Direction) {
+        let (long, short) = round((output.long, output.short));
+        (long, short, output.direction)
+    }
+
+    fn bars() -> Vec<Bar> {
+        vec![
+            (10.0, 9.0, 9.5),
+            (11.0, 10.0, 10.8),
+            (12.0, 11.0, 11.9),
+            (12.5, 11.5, 12.2),
+            (12.0, 10.0, 10.2),
+            (10.5, 9.0, 9.2),
+            (10.0, 8.0, 8.5),
+            (11.0, 9.5, 10.8),
+        ]
+        .into_iter()
+        .map(|(high, low, close)| Bar::new().high(high).low(low).close(close))
+        .collect()
+    }
+
+    #[test]
+    fn test_next_direction() {
+        use Direction::*;
+
+        let mut ce = Ce::new(3, 1.0).unwrap();
+        let expected = vec![
+            (9.0, 10.0, Long),
+            (9.75, 10.25, Long),
+            (10.78, 10.23, Long),
+            (11.39, 11.11, Long),
+            (10.84, 11.66, Short),
+            (10.92, 10.58, Short),
+            (10.21, 9.79, Short),
+            (8.86, 10.14, Long),
+        ];
+
+        for (bar, expected) in bars().iter().zip(expected) {
+            assert_eq!(triple(ce.next(bar)), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_ratchet() {
+        use Direction::*;
+
+        let mut ce = Ce::with_params(3, 3, 1.0, ChandelierExitExtremes::HighLow, true).unwrap();
+        assert!(ce.is_ratchet());
+
+        let bars = vec![
+            (11.5, 10.0, 10.5),
+            (12.5, 11.0, 12.0),
+            (12.5, 10.5, 11.5),
+            (13.0, 11.5, 12.0),
+            (13.5, 12.0, 13.0),
+            (12.5, 11.0, 11.5),
+            (12.0, 10.5, 11.0),
+            (13.0, 11.0, 12.0),
+        ];
+        let expected = vec![
+            (10.0, 11.5, Long),
+            // the short stop is not ratcheted while the direction is long
+            (10.75, 11.75, Long),
+            // the long stop does not decrease while the direction is long (10.62)
+            (10.75, 11.88, Long),
+            (11.31, 12.19, Long),
+            (11.91, 12.09, Long),
+            // after a flip the long stop follows the extremes again
+            (11.7, 12.8, Short),
+            (11.85, 12.15, Short),
+            // the short stop does not increase while the direction is short (12.32)
+            (11.18, 12.15, Short),
+        ];
+
+        for ((high, low, close), expected) in bars.into_iter().zip(expected) {
+            let bar = Bar::new().high(high).low(low).close(close);
+            assert_eq!(triple(ce.next(&bar)), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_close_extremes() {
+        use Direction::*;
+
+        let mut ce = Ce::with_params(3, 2, 1.0, ChandelierExitExtremes::Close, false).unwrap();
+        assert_eq!(ce.lookback_period(), 2);
+        assert_eq!(ce.extremes(), ChandelierExitExtremes::Close);
+
+        let bar1 = Bar::new().high(2).low(1).close(1.5);
+        assert_eq!(triple(ce.next(&bar1)), (0.5, 2.5, Long));
+
+        let bar2 = Bar::new().high(5).low(3).close(4);
+        assert_eq!(triple(ce.next(&bar2)), (1.75, 3.75, Long));
+
+        let bar3 = Bar::new().high(9).low(7).close(8);
+        assert_eq!(triple(ce.next(&bar3)), (4.38, 7.63, Long));
+
+        let bar4 = Bar::new().high(5).low(3).close(4);
+        assert_eq!(triple(ce.next(&bar4)), (3.69, 8.31, Short));
+    }
+
     #[test]
     fn test_reset() {
         let mut ce = Ce::new(5, 2.0).unwrap();
@@ -197,5 +400,9 @@ mod tests {
     fn test_display() {
         let indicator = Ce::new(10, 5.0).unwrap();
         assert_eq!(format!("{}", indicator), "CE(10, 5)");
+
+        let indicator =
+            Ce::with_params(10, 20, 5.0, ChandelierExitExtremes::HighLow, false).unwrap();
+        assert_eq!(format!("{}", indicator), "CE(10, 20, 5)");
     }
 }
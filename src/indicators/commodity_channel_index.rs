@@ -3,9 +3,9 @@ use std::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::errors::Result;
+use crate::errors::{Result, TaError};
 use crate::indicators::{MeanAbsoluteDeviation, SimpleMovingAverage};
-use crate::{Close, High, Low, Next, Period, Reset};
+use crate::{Close, High, Low, Next, Period, PriceSource, Reset};
 
 /// Commodity Channel Index (CCI)
 ///
@@ -17,11 +17,34 @@ use crate::{Close, High, Low, Next, Period, Reset};
 ///
 /// # Formula
 ///
-/// CCI(_period_) = (TP - SMA(_period_) of TP) / (MAD(_period_) * 0.015)
+/// CCI(_period_) = (TP - SMA(_period_) of TP) / (MAD(_period_) of TP * _constant_)
+///
+/// Where TP is the typical price (high + low + close) / 3 by default. Another price
+/// can be selected with a [PriceSource], and `Next<f64>` runs CCI on an arbitrary series.
 ///
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0). Default is 20.
+/// * _constant_ - scaling factor (greater than 0), so that most values fall between -100 and 100.
+///   Default is Lambert's 0.015.
+/// * _source_ - price of a data item, see [PriceSource]. Default is the typical price.
+///   Sources using the open price are rejected, so data items only need high, low and close;
+///   wrap CCI in a [WithSource](crate::WithSource) to run it on them.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::CommodityChannelIndex;
+/// use ta::{Next, PriceSource};
+///
+/// let mut cci = CommodityChannelIndex::new(3).unwrap();
+/// assert_eq!(cci.next(1.0), 0.0);
+/// assert_eq!(cci.next(3.0).round(), 67.0);
+///
+/// let mut cci = CommodityChannelIndex::with_params(3, 0.01, PriceSource::Close).unwrap();
+/// assert_eq!(cci.next(1.0), 0.0);
+/// assert_eq!(cci.next(3.0), 100.0);
+/// ```
 ///
 /// # Links
 ///
@@ -31,17 +54,37 @@ use crate::{Close, High, Low, Next, Period, Reset};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct CommodityChannelIndex {
+    constant: f64,
+    source: PriceSource,
     sma: SimpleMovingAverage,
     mad: MeanAbsoluteDeviation,
 }
 
 impl CommodityChannelIndex {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_params(period, 0.015, PriceSource::HLC3)
+    }
+
+    pub fn with_params(period: usize, constant: f64, source: PriceSource) -> Result<Self> {
+        if constant.is_nan() || constant <= 0.0 || source.uses_open() {
+            return Err(TaError::InvalidParameter);
+        }
+
         Ok(Self {
+            constant,
+            source,
             sma: SimpleMovingAverage::new(period)?,
             mad: MeanAbsoluteDeviation::new(period)?,
         })
     }
+
+    pub fn constant(&self) -> f64 {
+        self.constant
+    }
+
+    pub fn source(&self) -> PriceSource {
+        self.source
+    }
 }
 
 impl Period for CommodityChannelIndex {
@@ -50,19 +93,29 @@ impl Period for CommodityChannelIndex {
     }
 }
 
-impl<T: Close + High + Low> Next<&T> for CommodityChannelIndex {
+impl Next<f64> for CommodityChannelIndex {
     type Output = f64;
 
-    fn next(&mut self, input: &T) -> Self::Output {
-        let tp = (input.close() + input.high() + input.low()) / 3.0;
-        let sma = self.sma.next(tp);
+    fn next(&mut self, input: f64) -> Self::Output {
+        let sma = self.sma.next(input);
         let mad = self.mad.next(input);
 
         if mad == 0.0 {
             return 0.0;
         }
 
-        (tp - sma) / (mad * 0.015)
+        (input - sma) / (mad * self.constant)
+    }
+}
+
+impl<T: Close + High + Low> Next<&T> for CommodityChannelIndex {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        // Sources using the open price are rejected by `with_params`,
+        // only a deserialized indicator may carry one
+        let price = self.source.price_without_open(input).unwrap_or(f64::NAN);
+        self.next(price)
     }
 }
 
@@ -90,10 +143,17 @@ mod tests {
     use super::*;
     use crate::test_helper::*;
 
+    test_indicator!(CommodityChannelIndex);
+
     #[test]
     fn test_new() {
         assert!(CommodityChannelIndex::new(0).is_err());
         assert!(CommodityChannelIndex::new(1).is_ok());
+        assert!(CommodityChannelIndex::with_params(1, 0.0, PriceSource::Close).is_err());
+        assert!(CommodityChannelIndex::with_params(1, f64::NAN, PriceSource::Close).is_err());
+        assert!(CommodityChannelIndex::with_params(1, 0.02, PriceSource::Close).is_ok());
+        assert!(CommodityChannelIndex::with_params(1, 0.02, PriceSource::Open).is_err());
+        assert!(CommodityChannelIndex::with_params(1, 0.02, PriceSource::OHLC4).is_err());
     }
 
     #[test]
@@ -119,6 +179,75 @@ mod tests {
         assert_eq!(round(cci.next(&bar6)), -126.126);
     }
 
+    #[test]
+    fn test_next_bar_typical_price() {
+        // The mean absolute deviation is computed on the typical price too
+        let mut cci = CommodityChannelIndex::new(3).unwrap();
+
+        let bar1 = Bar::new().high(3).low(1).close(2.9);
+        assert_eq!(round(cci.next(&bar1)), 0.0);
+
+        let bar2 = Bar::new().high(6).low(4).close(4.1);
+        assert_eq!(round(cci.next(&bar2)), 66.667);
+
+        // Previous versions took the mean absolute deviation of the close (2.9, 4.1, 3.2)
+        // and returned 22.222 for the same bars
+        let bar3 = Bar::new().high(5).low(3).close(3.2);
+        let value = round(cci.next(&bar3));
+        assert_eq!(value, 12.174);
+        assert_ne!(value, 22.222);
+    }
+
+    #[test]
+    fn test_next_f64() {
+        let mut cci = CommodityChannelIndex::new(3).unwrap();
+
+        assert_eq!(round(cci.next(1.0)), 0.0);
+        assert_eq!(round(cci.next(3.0)), 66.667);
+        assert_eq!(round(cci.next(2.0)), 0.0);
+        assert_eq!(round(cci.next(6.0)), 100.0);
+    }
+
+    #[test]
+    fn test_with_params() {
+        let mut cci = CommodityChannelIndex::with_params(3, 0.01, PriceSource::Close).unwrap();
+        assert_eq!(cci.constant(), 0.01);
+        assert_eq!(cci.source(), PriceSource::Close);
+
+        let bar1 = Bar::new().high(3).low(0).close(1);
+        assert_eq!(round(cci.next(&bar1)), 0.0);
+
+        let bar2 = Bar::new().high(4).low(1).close(3);
+        assert_eq!(round(cci.next(&bar2)), 100.0);
+    }
+
+    #[test]
+    fn test_next_without_open() {
+        struct Hlc(f64, f64, f64);
+
+        impl High for Hlc {
+            fn high(&self) -> f64 {
+                self.0
+            }
+        }
+
+        impl Low for Hlc {
+            fn low(&self) -> f64 {
+                self.1
+            }
+        }
+
+        impl Close for Hlc {
+            fn close(&self) -> f64 {
+                self.2
+            }
+        }
+
+        let mut cci = CommodityChannelIndex::new(3).unwrap();
+        assert_eq!(round(cci.next(&Hlc(3.0, 1.0, 2.9))), 0.0);
+        assert_eq!(round(cci.next(&Hlc(6.0, 4.0, 4.1))), 66.667);
+    }
+
     #[test]
     fn test_reset() {
         let mut cci = CommodityChannelIndex::new(5).unwrap();
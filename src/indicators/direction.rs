@@ -0,0 +1,12 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Side of the market signaled by trend following indicators.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Uptrend, long positions are held.
+    Long,
+    /// Downtrend, short positions are held.
+    Short,
+}
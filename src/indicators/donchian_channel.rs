@@ -0,0 +1,236 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum};
+use crate::{High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Donchian Channel (DC).
+///
+/// Developed by Richard Donchian, the channel is formed by the highest high and the lowest
+/// low of the last _period_ bars. A close above the upper band or below the lower band is
+/// the breakout signal of the Turtle trading system.
+///
+/// # Formula
+///
+/// * _Upper Band_ = highest high of the last _period_ bars
+/// * _Lower Band_ = lowest low of the last _period_ bars
+/// * _Middle Band_ = (_Upper Band_ + _Lower Band_) / 2
+///
+/// The breakout flags compare the current bar with the channel of the previous _period_
+/// bars, which does not include the current bar:
+///
+/// * _new_high_ - the high is above the previous upper band
+/// * _new_low_ - the low is below the previous lower band
+///
+/// Both flags are false on the first bar.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::DonchianChannel;
+/// use ta::Next;
+///
+/// let mut dc = DonchianChannel::new(2).unwrap();
+/// dc.next(5.0);
+/// let out = dc.next(6.0);
+/// assert_eq!(out.upper, 6.0);
+/// assert_eq!(out.middle, 5.5);
+/// assert_eq!(out.lower, 5.0);
+/// assert!(out.new_high);
+/// assert!(!out.new_low);
+/// ```
+///
+/// # Links
+///
+/// * [Donchian Channels, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:price_channels)
+///
+#[doc(alias = "DC")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DonchianChannel {
+    max: Maximum,
+    min: Minimum,
+    // Upper and lower band of the previous bar
+    previous: Option<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DonchianChannelOutput {
+    pub upper: f64,
+    pub middle: f64,
+    pub lower: f64,
+    /// Whether the high is above the upper band of the previous bars.
+    pub new_high: bool,
+    /// Whether the low is below the lower band of the previous bars.
+    pub new_low: bool,
+}
+
+impl From<DonchianChannelOutput> for (f64, f64, f64, bool, bool) {
+    fn from(dc: DonchianChannelOutput) -> Self {
+        (dc.upper, dc.middle, dc.lower, dc.new_high, dc.new_low)
+    }
+}
+
+impl DonchianChannel {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            max: Maximum::new(period)?,
+            min: Minimum::new(period)?,
+            previous: None,
+        })
+    }
+
+    fn update(&mut self, high: f64, low: f64) -> DonchianChannelOutput {
+        let (new_high, new_low) = match self.previous {
+            Some((upper, lower)) => (high > upper, low < lower),
+            None => (false, false),
+        };
+
+        let upper = self.max.next(high);
+        let lower = self.min.next(low);
+        self.previous = Some((upper, lower));
+
+        DonchianChannelOutput {
+            upper,
+            middle: (upper + lower) / 2.0,
+            lower,
+            new_high,
+            new_low,
+        }
+    }
+}
+
+impl Period for DonchianChannel {
+    fn period(&self) -> usize {
+        self.max.period()
+    }
+}
+
+impl Next<f64> for DonchianChannel {
+    type Output = DonchianChannelOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.update(input, input)
+    }
+}
+
+impl<T: High + Low> Next<&T> for DonchianChannel {
+    type Output = DonchianChannelOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.update(input.high(), input.low())
+    }
+}
+
+impl Reset for DonchianChannel {
+    fn reset(&mut self) {
+        self.max.reset();
+        self.min.reset();
+        self.previous = None;
+    }
+}
+
+impl Default for DonchianChannel {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for DonchianChannel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DC({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(DonchianChannel);
+
+    fn bar(high: f64, low: f64) -> Bar {
+        Bar::new().high(high).low(low)
+    }
+
+    fn tuple(output: DonchianChannelOutput) -> (f64, f64, f64, bool, bool) {
+        let (upper, middle, lower, new_high, new_low) = output.into();
+        (round(upper), round(middle), round(lower), new_high, new_low)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(DonchianChannel::new(0).is_err());
+        assert!(DonchianChannel::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_with_f64() {
+        let mut dc = DonchianChannel::new(2).unwrap();
+
+        assert_eq!(tuple(dc.next(5.0)), (5.0, 5.0, 5.0, false, false));
+        assert_eq!(tuple(dc.next(6.0)), (6.0, 5.5, 5.0, true, false));
+        assert_eq!(tuple(dc.next(4.0)), (6.0, 5.0, 4.0, false, true));
+        assert_eq!(tuple(dc.next(4.0)), (4.0, 4.0, 4.0, false, false));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut dc = DonchianChannel::new(3).unwrap();
+
+        let test_data = vec![
+            // high, low, upper, middle, lower, new high, new low
+            (10.0, 8.0, 10.0, 9.0, 8.0, false, false),
+            (11.0, 9.0, 11.0, 9.5, 8.0, true, false),
+            (10.5, 7.0, 11.0, 9.0, 7.0, false, true),
+            (10.0, 9.0, 11.0, 9.0, 7.0, false, false),
+            (9.0, 8.5, 10.5, 8.75, 7.0, false, false),
+            // the previous window no longer holds the high of 11
+            (10.8, 8.6, 10.8, 9.65, 8.5, true, false),
+            // touching the bands is not a breakout
+            (10.8, 8.5, 10.8, 9.65, 8.5, false, false),
+        ];
+
+        for (high, low, upper, middle, lower, new_high, new_low) in test_data {
+            assert_eq!(
+                tuple(dc.next(&bar(high, low))),
+                (upper, middle, lower, new_high, new_low)
+            );
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dc = DonchianChannel::new(3).unwrap();
+
+        dc.next(&bar(10.0, 8.0));
+        dc.next(&bar(11.0, 9.0));
+
+        dc.reset();
+        assert_eq!(
+            tuple(dc.next(&bar(12.0, 7.0))),
+            (12.0, 9.5, 7.0, false, false)
+        );
+        assert_eq!(
+            tuple(dc.next(&bar(11.0, 6.0))),
+            (12.0, 9.0, 6.0, false, true)
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        DonchianChannel::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = DonchianChannel::new(20).unwrap();
+        assert_eq!(format!("{}", indicator), "DC(20)");
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Double exponential moving average (DEMA).
+///
+/// Developed by Patrick Mulloy, DEMA reduces the lag of an
+/// [EMA](struct.ExponentialMovingAverage.html) by subtracting the EMA of the EMA
+/// from twice the EMA.
+///
+/// # Formula
+///
+/// DEMA<sub>t</sub> = 2 * EMA<sub>t</sub> - EMA(EMA)<sub>t</sub>
+///
+/// Where:
+///
+/// * _EMA_ - [exponential moving average](struct.ExponentialMovingAverage.html) with _period_
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the EMAs (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::DoubleExponentialMovingAverage;
+/// use ta::Next;
+///
+/// let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+/// assert_eq!(dema.next(2.0), 2.0);
+/// assert_eq!(dema.next(5.0), 4.25);
+/// assert_eq!(dema.next(1.0), 2.0);
+/// assert_eq!(dema.next(6.25), 5.125);
+/// ```
+///
+/// # Links
+///
+/// * [Double exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Double_exponential_moving_average)
+///
+#[doc(alias = "DEMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct DoubleExponentialMovingAverage {
+    ema1: Ema,
+    ema2: Ema,
+}
+
+impl DoubleExponentialMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema1: Ema::new(period)?,
+            ema2: Ema::new(period)?,
+        })
+    }
+}
+
+impl Period for DoubleExponentialMovingAverage {
+    fn period(&self) -> usize {
+        self.ema1.period()
+    }
+}
+
+impl Next<f64> for DoubleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let ema1 = self.ema1.next(input);
+        let ema2 = self.ema2.next(ema1);
+        2.0 * ema1 - ema2
+    }
+}
+
+impl<T: Close> Next<&T> for DoubleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for DoubleExponentialMovingAverage {
+    fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+    }
+}
+
+impl Default for DoubleExponentialMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for DoubleExponentialMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "DEMA({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(DoubleExponentialMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(DoubleExponentialMovingAverage::new(0).is_err());
+        assert!(DoubleExponentialMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+
+        assert_eq!(dema.next(2.0), 2.0);
+        assert_eq!(dema.next(5.0), 4.25);
+        assert_eq!(dema.next(1.0), 2.0);
+        assert_eq!(dema.next(6.25), 5.125);
+        assert_eq!(dema.next(4.0), 4.5);
+        assert_eq!(round(dema.next(3.0)), 3.469);
+
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+        let bar1 = Bar::new().close(2);
+        let bar2 = Bar::new().close(5);
+        assert_eq!(dema.next(&bar1), 2.0);
+        assert_eq!(dema.next(&bar2), 4.25);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut dema = DoubleExponentialMovingAverage::new(3).unwrap();
+
+        assert_eq!(dema.next(2.0), 2.0);
+        assert_eq!(dema.next(5.0), 4.25);
+
+        dema.reset();
+        assert_eq!(dema.next(5.0), 5.0);
+    }
+
+    #[test]
+    fn test_default() {
+        DoubleExponentialMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let dema = DoubleExponentialMovingAverage::new(7).unwrap();
+        assert_eq!(format!("{}", dema), "DEMA(7)");
+    }
+}
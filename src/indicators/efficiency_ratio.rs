@@ -1,7 +1,8 @@
 use std::fmt;
 
-use crate::errors::{Result, TaError};
+use crate::errors::Result;
 use crate::traits::{Close, Next, Period, Reset};
+use crate::RollingWindow;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -28,34 +29,24 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(er.next(18.0), 0.8);
 /// assert_eq!(er.next(19.0), 0.75);
 /// ```
-
 #[doc(alias = "ER")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct EfficiencyRatio {
-    period: usize,
-    index: usize,
-    count: usize,
-    deque: Box<[f64]>,
+    window: RollingWindow,
 }
 
 impl EfficiencyRatio {
     pub fn new(period: usize) -> Result<Self> {
-        match period {
-            0 => Err(TaError::InvalidParameter),
-            _ => Ok(Self {
-                period,
-                index: 0,
-                count: 0,
-                deque: vec![0.0; period].into_boxed_slice(),
-            }),
-        }
+        Ok(Self {
+            window: RollingWindow::new(period)?,
+        })
     }
 }
 
 impl Period for EfficiencyRatio {
     fn period(&self) -> usize {
-        self.period
+        self.window.capacity()
     }
 }
 
@@ -63,29 +54,18 @@ impl Next<f64> for EfficiencyRatio {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> f64 {
-        let first = if self.count >= self.period {
-            self.deque[self.index]
-        } else {
-            self.count += 1;
-            self.deque[0]
-        };
-        self.deque[self.index] = input;
-
-        self.index = if self.index + 1 < self.period {
-            self.index + 1
-        } else {
-            0
+        let first = match self.window.push(input) {
+            Some(evicted) => evicted,
+            // The very first input moves the price from nowhere, treat it as efficient
+            None if self.window.len() == 1 => return 1.0,
+            None => self.window.oldest().unwrap(),
         };
 
         let mut volatility = 0.0;
         let mut previous = first;
-        for n in &self.deque[self.index..self.count] {
+        for n in self.window.iter() {
             volatility += (previous - n).abs();
-            previous = *n;
-        }
-        for n in &self.deque[0..self.index] {
-            volatility += (previous - n).abs();
-            previous = *n;
+            previous = n;
         }
 
         (first - input).abs() / volatility
@@ -102,11 +82,7 @@ impl<T: Close> Next<&T> for EfficiencyRatio {
 
 impl Reset for EfficiencyRatio {
     fn reset(&mut self) {
-        self.index = 0;
-        self.count = 0;
-        for i in 0..self.period {
-            self.deque[i] = 0.0;
-        }
+        self.window.clear();
     }
 }
 
@@ -118,7 +94,7 @@ impl Default for EfficiencyRatio {
 
 impl fmt::Display for EfficiencyRatio {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ER({})", self.period)
+        write!(f, "ER({})", self.period())
     }
 }
 
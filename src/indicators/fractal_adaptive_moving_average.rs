@@ -0,0 +1,259 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{High, Low, Next, Period, Reset, RollingWindow};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Fractal Adaptive Moving Average (FRAMA).
+///
+/// Developed by John Ehlers, an exponential moving average whose smoothing factor is
+/// derived from the fractal dimension of the prices over the last _period_ bars. A
+/// trending market has a dimension close to 1 and gets a fast average, a choppy market
+/// has a dimension close to 2 and gets a slow one.
+///
+/// # Formula
+///
+/// FRAMA<sub>t</sub> = FRAMA<sub>t-1</sub> + α<sub>t</sub> * (price<sub>t</sub> - FRAMA<sub>t-1</sub>)
+///
+/// α<sub>t</sub> = exp(-4.6 * (D - 1)), limited to the range from 0.01 to 1
+///
+/// D = (ln(N1 + N2) - ln(N3)) / ln(2)
+///
+/// Where:
+///
+/// * _N1_ - (highest high - lowest low) / (_period_ / 2) of the older half of the bars
+/// * _N2_ - (highest high - lowest low) / (_period_ / 2) of the newer half of the bars
+/// * _N3_ - (highest high - lowest low) / _period_ of all the bars
+/// * _price_ - median price (high + low) / 2 of the bar, as in Ehlers' paper, or the
+///   input itself for `f64` inputs
+///
+/// Until _period_ bars are received, the price is taken as is. When one of the ranges
+/// is 0, the previous α is kept.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (even integer greater than 0). Default is 16.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::FractalAdaptiveMovingAverage;
+/// use ta::Next;
+///
+/// let mut frama = FractalAdaptiveMovingAverage::new(2).unwrap();
+/// assert_eq!(frama.next(10.0), 10.0);
+/// assert_eq!(frama.next(11.0), 11.0);
+/// assert_eq!(frama.alpha(), Some(1.0));
+/// ```
+///
+/// # Links
+///
+/// * [FRAMA, John Ehlers](https://www.mesasoftware.com/papers/FRAMA.pdf)
+///
+#[doc(alias = "FRAMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct FractalAdaptiveMovingAverage {
+    highs: RollingWindow,
+    lows: RollingWindow,
+    current: f64,
+    alpha: Option<f64>,
+}
+
+impl FractalAdaptiveMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ if period % 2 == 1 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                highs: RollingWindow::new(period)?,
+                lows: RollingWindow::new(period)?,
+                current: 0.0,
+                alpha: None,
+            }),
+        }
+    }
+
+    /// Returns the smoothing factor applied to the last input, `None` before the first input.
+    pub fn alpha(&self) -> Option<f64> {
+        self.alpha
+    }
+
+    fn range(&self, skip: usize, take: usize) -> f64 {
+        let high = self
+            .highs
+            .iter()
+            .skip(skip)
+            .take(take)
+            .fold(f64::MIN, f64::max);
+        let low = self
+            .lows
+            .iter()
+            .skip(skip)
+            .take(take)
+            .fold(f64::MAX, f64::min);
+        (high - low) / take as f64
+    }
+
+    fn next_alpha(&self) -> f64 {
+        if !self.highs.is_full() {
+            return 1.0;
+        }
+
+        let period = self.period();
+        let half = period / 2;
+        let n1 = self.range(0, half);
+        let n2 = self.range(half, half);
+        let n3 = self.range(0, period);
+
+        if n1 > 0.0 && n2 > 0.0 && n3 > 0.0 {
+            let dimension = ((n1 + n2).ln() - n3.ln()) / std::f64::consts::LN_2;
+            (-4.6 * (dimension - 1.0)).exp().clamp(0.01, 1.0)
+        } else {
+            self.alpha.unwrap_or(1.0)
+        }
+    }
+
+    fn update(&mut self, high: f64, low: f64, price: f64) -> f64 {
+        self.highs.push(high);
+        self.lows.push(low);
+
+        let alpha = self.next_alpha();
+        self.alpha = Some(alpha);
+        self.current += alpha * (price - self.current);
+        self.current
+    }
+}
+
+impl Period for FractalAdaptiveMovingAverage {
+    fn period(&self) -> usize {
+        self.highs.capacity()
+    }
+}
+
+impl Next<f64> for FractalAdaptiveMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.update(input, input, input)
+    }
+}
+
+impl<T: High + Low> Next<&T> for FractalAdaptiveMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let (high, low) = (input.high(), input.low());
+        self.update(high, low, (high + low) / 2.0)
+    }
+}
+
+impl Reset for FractalAdaptiveMovingAverage {
+    fn reset(&mut self) {
+        self.highs.clear();
+        self.lows.clear();
+        self.current = 0.0;
+        self.alpha = None;
+    }
+}
+
+impl Default for FractalAdaptiveMovingAverage {
+    fn default() -> Self {
+        Self::new(16).unwrap()
+    }
+}
+
+impl fmt::Display for FractalAdaptiveMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FRAMA({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(FractalAdaptiveMovingAverage);
+
+    fn bar(high: f64, low: f64) -> Bar {
+        Bar::new().high(high).low(low)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(FractalAdaptiveMovingAverage::new(0).is_err());
+        assert!(FractalAdaptiveMovingAverage::new(1).is_err());
+        assert!(FractalAdaptiveMovingAverage::new(3).is_err());
+        assert!(FractalAdaptiveMovingAverage::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut frama = FractalAdaptiveMovingAverage::new(4).unwrap();
+        assert_eq!(frama.alpha(), None);
+
+        let test_data = vec![
+            // input, frama, alpha
+            (10.0, 10.0, 1.0),
+            (11.0, 11.0, 1.0),
+            (12.0, 12.0, 1.0),
+            (11.0, 11.0, 1.0),
+            (9.0, 9.0, 1.0),
+            (9.0, 9.0, 1.0),
+            (10.0, 9.068, 0.068),
+            (14.0, 9.402, 0.068),
+        ];
+
+        for (input, value, alpha) in test_data {
+            assert_eq!(round(frama.next(input)), value);
+            assert_eq!(frama.alpha().map(round), Some(alpha));
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut frama = FractalAdaptiveMovingAverage::new(4).unwrap();
+
+        let test_data = vec![
+            // high, low, frama, alpha
+            (11.0, 9.0, 10.0, 1.0),
+            (12.0, 10.0, 11.0, 1.0),
+            (13.0, 11.0, 12.0, 1.0),
+            (12.0, 8.0, 11.912, 0.044),
+            (10.0, 8.0, 11.599, 0.107),
+            (11.0, 9.0, 11.529, 0.044),
+        ];
+
+        for (high, low, value, alpha) in test_data {
+            assert_eq!(round(frama.next(&bar(high, low))), value);
+            assert_eq!(frama.alpha().map(round), Some(alpha));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut frama = FractalAdaptiveMovingAverage::new(2).unwrap();
+
+        frama.next(&bar(11.0, 9.0));
+        frama.next(&bar(12.0, 10.0));
+        assert_eq!(round(frama.alpha().unwrap()), 0.148);
+
+        frama.reset();
+        assert_eq!(frama.alpha(), None);
+        assert_eq!(frama.next(&bar(12.0, 10.0)), 11.0);
+        assert_eq!(frama.alpha(), Some(1.0));
+    }
+
+    #[test]
+    fn test_default() {
+        FractalAdaptiveMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let frama = FractalAdaptiveMovingAverage::new(16).unwrap();
+        assert_eq!(format!("{}", frama), "FRAMA(16)");
+    }
+}
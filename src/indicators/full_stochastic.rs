@@ -0,0 +1,232 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::moving_average::MovingAverage;
+use crate::indicators::{FastStochastic, MovingAverageType};
+use crate::{Close, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Full stochastic oscillator.
+///
+/// A fast stochastic oscillator, whose %K is smoothed with a moving average,
+/// along with %D, a moving average of the smoothed %K used as a signal line.
+/// With a %K smoothing period of 1 it is the fast stochastic, with a %K smoothing
+/// period of 3 it is the classic slow stochastic.
+///
+/// # Formula
+///
+/// * _%K_ = MA(Fast Stochastic(_period_), _k_period_)
+/// * _%D_ = MA(_%K_, _d_period_)
+///
+/// # Parameters
+///
+/// * _period_ - number of periods for fast stochastic (integer greater than 0). Default is 14.
+/// * _k_period_ - smoothing period of %K (integer greater than 0). Default is 3.
+/// * _d_period_ - period of %D (integer greater than 0). Default is 3.
+/// * _smoothing_ - kind of moving average, see [MovingAverageType]. Default is SMA.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{FullStochastic, MovingAverageType};
+/// use ta::Next;
+///
+/// let mut stoch = FullStochastic::new(3, 2, 2).unwrap();
+/// let out = stoch.next(10.0);
+/// assert_eq!((out.k, out.d), (50.0, 50.0));
+/// let out = stoch.next(50.0);
+/// assert_eq!((out.k, out.d), (75.0, 62.5));
+///
+/// let mut stoch =
+///     FullStochastic::with_smoothing(3, 2, 2, MovingAverageType::Exponential).unwrap();
+/// assert_eq!(stoch.next(10.0).k, 50.0);
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic Oscillator, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:stochastic_oscillator_fast_slow_and_full)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct FullStochastic {
+    fast_stochastic: FastStochastic,
+    k: MovingAverage,
+    d: MovingAverage,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FullStochasticOutput {
+    pub k: f64,
+    pub d: f64,
+}
+
+impl From<FullStochasticOutput> for (f64, f64) {
+    fn from(stoch: FullStochasticOutput) -> Self {
+        (stoch.k, stoch.d)
+    }
+}
+
+impl FullStochastic {
+    pub fn new(period: usize, k_period: usize, d_period: usize) -> Result<Self> {
+        Self::with_smoothing(period, k_period, d_period, MovingAverageType::Simple)
+    }
+
+    pub fn with_smoothing(
+        period: usize,
+        k_period: usize,
+        d_period: usize,
+        smoothing: MovingAverageType,
+    ) -> Result<Self> {
+        Ok(Self {
+            fast_stochastic: FastStochastic::new(period)?,
+            k: MovingAverage::new(smoothing, k_period)?,
+            d: MovingAverage::new(smoothing, d_period)?,
+        })
+    }
+
+    pub fn smoothing(&self) -> MovingAverageType {
+        self.k.kind()
+    }
+
+    fn output(&mut self, fast_k: f64) -> FullStochasticOutput {
+        let k = self.k.next(fast_k);
+        let d = self.d.next(k);
+        FullStochasticOutput { k, d }
+    }
+}
+
+impl Period for FullStochastic {
+    fn period(&self) -> usize {
+        self.fast_stochastic.period()
+    }
+}
+
+impl Next<f64> for FullStochastic {
+    type Output = FullStochasticOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let fast_k = self.fast_stochastic.next(input);
+        self.output(fast_k)
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for FullStochastic {
+    type Output = FullStochasticOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let fast_k = self.fast_stochastic.next(input);
+        self.output(fast_k)
+    }
+}
+
+impl Reset for FullStochastic {
+    fn reset(&mut self) {
+        self.fast_stochastic.reset();
+        self.k.reset();
+        self.d.reset();
+    }
+}
+
+impl Default for FullStochastic {
+    fn default() -> Self {
+        Self::new(14, 3, 3).unwrap()
+    }
+}
+
+impl fmt::Display for FullStochastic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "FULL_STOCH({}, {}, {})",
+            self.fast_stochastic.period(),
+            self.k.period(),
+            self.d.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(FullStochastic);
+
+    fn tuple(output: FullStochasticOutput) -> (f64, f64) {
+        let (k, d) = output.into();
+        (round(k), round(d))
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(FullStochastic::new(0, 1, 1).is_err());
+        assert!(FullStochastic::new(1, 0, 1).is_err());
+        assert!(FullStochastic::new(1, 1, 0).is_err());
+        assert!(FullStochastic::new(1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next_with_f64() {
+        let mut stoch = FullStochastic::new(3, 2, 2).unwrap();
+
+        assert_eq!(tuple(stoch.next(10.0)), (50.0, 50.0));
+        assert_eq!(tuple(stoch.next(50.0)), (75.0, 62.5));
+        assert_eq!(tuple(stoch.next(50.0)), (100.0, 87.5));
+        assert_eq!(tuple(stoch.next(30.0)), (50.0, 75.0));
+        assert_eq!(tuple(stoch.next(55.0)), (50.0, 50.0));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let test_data = vec![
+            // high, low , close, k, d
+            (30.0, 10.0, 25.0, 75.0, 75.0),
+            (20.0, 20.0, 20.0, 62.5, 68.75),
+            (40.0, 20.0, 16.0, 35.0, 48.75),
+            (35.0, 15.0, 19.0, 18.0, 26.5),
+            (30.0, 20.0, 25.0, 28.0, 23.0),
+        ];
+
+        let mut stoch = FullStochastic::new(3, 2, 2).unwrap();
+
+        for (high, low, close, k, d) in test_data {
+            let input_bar = Bar::new().high(high).low(low).close(close);
+            assert_eq!(tuple(stoch.next(&input_bar)), (k, d));
+        }
+    }
+
+    #[test]
+    fn test_next_with_smoothing() {
+        let mut stoch =
+            FullStochastic::with_smoothing(3, 1, 2, MovingAverageType::Exponential).unwrap();
+        assert_eq!(stoch.smoothing(), MovingAverageType::Exponential);
+
+        // With a %K smoothing period of 1 %K is the fast stochastic
+        assert_eq!(tuple(stoch.next(10.0)), (50.0, 50.0));
+        assert_eq!(tuple(stoch.next(50.0)), (100.0, 83.333));
+        assert_eq!(tuple(stoch.next(50.0)), (100.0, 94.444));
+        assert_eq!(tuple(stoch.next(30.0)), (0.0, 31.481));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stoch = FullStochastic::new(3, 2, 2).unwrap();
+        assert_eq!(tuple(stoch.next(10.0)), (50.0, 50.0));
+        assert_eq!(tuple(stoch.next(50.0)), (75.0, 62.5));
+
+        stoch.reset();
+        assert_eq!(tuple(stoch.next(10.0)), (50.0, 50.0));
+    }
+
+    #[test]
+    fn test_default() {
+        FullStochastic::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let stoch = FullStochastic::new(14, 3, 5).unwrap();
+        assert_eq!(format!("{}", stoch), "FULL_STOCH(14, 3, 5)");
+    }
+}
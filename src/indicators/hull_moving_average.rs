@@ -0,0 +1,182 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::WeightedMovingAverage as Wma;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Hull moving average (HMA).
+///
+/// Developed by Alan Hull, HMA is a fast and smooth moving average. The lag of a
+/// [WMA](struct.WeightedMovingAverage.html) is offset by comparing the WMAs over the full
+/// and the half period, then the result is smoothed by a WMA over the square root of the period.
+///
+/// # Formula
+///
+/// HMA<sub>t</sub> = WMA(2 * WMA(_period_ / 2) - WMA(_period_), √_period_)
+///
+/// Both _period_ / 2 and √_period_ are rounded down to integers.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 1). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::HullMovingAverage;
+/// use ta::Next;
+///
+/// let mut hma = HullMovingAverage::new(2).unwrap();
+/// assert_eq!(hma.next(10.0), 10.0);
+/// assert_eq!(hma.next(13.0), 14.0);
+/// assert_eq!(hma.next(16.0), 17.0);
+/// ```
+///
+/// # Links
+///
+/// * [Hull Moving Average, Alan Hull](https://alanhull.com/hull-moving-average)
+///
+#[doc(alias = "HMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct HullMovingAverage {
+    half: Wma,
+    full: Wma,
+    smoothing: Wma,
+}
+
+impl HullMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 | 1 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                half: Wma::new(period / 2)?,
+                full: Wma::new(period)?,
+                smoothing: Wma::new((period as f64).sqrt() as usize)?,
+            }),
+        }
+    }
+}
+
+impl Period for HullMovingAverage {
+    fn period(&self) -> usize {
+        self.full.period()
+    }
+}
+
+impl Next<f64> for HullMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let half = self.half.next(input);
+        let full = self.full.next(input);
+        self.smoothing.next(2.0 * half - full)
+    }
+}
+
+impl<T: Close> Next<&T> for HullMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for HullMovingAverage {
+    fn reset(&mut self) {
+        self.half.reset();
+        self.full.reset();
+        self.smoothing.reset();
+    }
+}
+
+impl Default for HullMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for HullMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HMA({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(HullMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(HullMovingAverage::new(0).is_err());
+        assert!(HullMovingAverage::new(1).is_err());
+        assert!(HullMovingAverage::new(2).is_ok());
+    }
+
+    #[test]
+    fn test_periods() {
+        let hma = HullMovingAverage::new(4).unwrap();
+        assert_eq!((hma.half.period(), hma.smoothing.period()), (2, 2));
+
+        let hma = HullMovingAverage::new(9).unwrap();
+        assert_eq!((hma.half.period(), hma.smoothing.period()), (4, 3));
+
+        let hma = HullMovingAverage::new(15).unwrap();
+        assert_eq!((hma.half.period(), hma.smoothing.period()), (7, 3));
+
+        let hma = HullMovingAverage::new(16).unwrap();
+        assert_eq!((hma.half.period(), hma.smoothing.period()), (8, 4));
+    }
+
+    #[test]
+    fn test_next() {
+        let inputs = [10.0, 13.0, 16.0, 14.0, 12.0, 15.0, 18.0, 17.0];
+
+        let mut hma = HullMovingAverage::new(4).unwrap();
+        let expected = [10.0, 11.333, 14.667, 15.556, 13.0, 13.278, 17.0, 18.344];
+        for (input, expected) in inputs.iter().zip(expected.iter()) {
+            assert_eq!(round(hma.next(*input)), *expected);
+        }
+
+        let mut hma = HullMovingAverage::new(5).unwrap();
+        let expected = [10.0, 11.333, 14.667, 15.556, 13.111, 13.333, 17.111, 18.667];
+        for (input, expected) in inputs.iter().zip(expected.iter()) {
+            assert_eq!(round(hma.next(*input)), *expected);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut hma = HullMovingAverage::new(2).unwrap();
+
+        assert_eq!(hma.next(&Bar::new().close(10)), 10.0);
+        assert_eq!(hma.next(&Bar::new().close(13)), 14.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hma = HullMovingAverage::new(2).unwrap();
+
+        assert_eq!(hma.next(10.0), 10.0);
+        assert_eq!(hma.next(13.0), 14.0);
+
+        hma.reset();
+        assert_eq!(hma.next(13.0), 13.0);
+    }
+
+    #[test]
+    fn test_default() {
+        HullMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let hma = HullMovingAverage::new(16).unwrap();
+        assert_eq!(format!("{}", hma), "HMA(16)");
+    }
+}
@@ -1,7 +1,8 @@
 use std::fmt;
 
 use crate::errors::Result;
-use crate::indicators::{AverageTrueRange, ExponentialMovingAverage};
+use crate::indicators::moving_average::MovingAverage;
+use crate::indicators::{MovingAverageType, TrueRange};
 use crate::{Close, High, Low, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -22,10 +23,22 @@ use serde::{Deserialize, Serialize};
 ///  * _KC<sub>Upper Band</sub>_ = EMA + ATR of observation * multipler (usually 2.0)
 ///  * _KC<sub>Lower Band</sub>_ = EMA - ATR of observation * multipler (usually 2.0)
 ///
+/// The original version by Chester Keltner ([KeltnerChannelMethod::Classic]) uses
+/// the SMA of the typical price as middle band and the SMA of the high - low range
+/// instead of ATR. Fed with `f64` values, which have no range, its bands are collapsed.
+///
+/// # Parameters
+///
+/// * _period_ - period of the middle band (integer greater than 0). Default is 10.
+/// * _atr_period_ - period of ATR, or of the average range for the classic formula
+///   (integer greater than 0). Default is the same as _period_.
+/// * _multiplier_ - ATR factor. Default is 2.
+/// * _method_ - see [KeltnerChannelMethod]. Default is ATR.
+///
 /// # Example
 ///
 ///```
-/// use ta::indicators::{KeltnerChannel, KeltnerChannelOutput};
+/// use ta::indicators::{KeltnerChannel, KeltnerChannelMethod, KeltnerChannelOutput};
 /// use ta::Next;
 ///
 /// let mut kc = KeltnerChannel::new(3, 2.0_f64).unwrap();
@@ -41,19 +54,35 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(out_1.average, 3.5);
 /// assert_eq!(out_1.upper, 6.5);
 /// assert_eq!(out_1.lower, 0.5);
+///
+/// // EMA(20) middle band with ATR(10) bands, as on StockCharts
+/// let kc = KeltnerChannel::with_params(20, 10, 2.0, KeltnerChannelMethod::Atr).unwrap();
+/// assert_eq!(kc.atr_period(), 10);
 /// ```
 ///
 /// # Links
 ///
 /// * [Keltner channel, Wikipedia](https://en.wikipedia.org/wiki/Keltner_channel)
+/// * [Keltner Channels, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:keltner_channels)
 #[doc(alias = "KC")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct KeltnerChannel {
-    period: usize,
     multiplier: f64,
-    atr: AverageTrueRange,
-    ema: ExponentialMovingAverage,
+    method: KeltnerChannelMethod,
+    true_range: TrueRange,
+    average: MovingAverage,
+    band: MovingAverage,
+}
+
+/// Formula of the [KeltnerChannel].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeltnerChannelMethod {
+    /// EMA of the typical price ± ATR * multiplier.
+    Atr,
+    /// SMA of the typical price ± SMA of the high - low range * multiplier (Chester Keltner).
+    Classic,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -65,22 +94,56 @@ pub struct KeltnerChannelOutput {
 
 impl KeltnerChannel {
     pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Self::with_params(period, period, multiplier, KeltnerChannelMethod::Atr)
+    }
+
+    pub fn with_params(
+        period: usize,
+        atr_period: usize,
+        multiplier: f64,
+        method: KeltnerChannelMethod,
+    ) -> Result<Self> {
+        let kind = match method {
+            KeltnerChannelMethod::Atr => MovingAverageType::Exponential,
+            KeltnerChannelMethod::Classic => MovingAverageType::Simple,
+        };
+
         Ok(Self {
-            period,
             multiplier,
-            atr: AverageTrueRange::new(period)?,
-            ema: ExponentialMovingAverage::new(period)?,
+            method,
+            true_range: TrueRange::new(),
+            average: MovingAverage::new(kind, period)?,
+            band: MovingAverage::new(kind, atr_period)?,
         })
     }
 
     pub fn multiplier(&self) -> f64 {
         self.multiplier
     }
+
+    pub fn atr_period(&self) -> usize {
+        self.band.period()
+    }
+
+    pub fn method(&self) -> KeltnerChannelMethod {
+        self.method
+    }
+
+    fn output(&mut self, price: f64, range: f64) -> KeltnerChannelOutput {
+        let average = self.average.next(price);
+        let band = self.band.next(range) * self.multiplier;
+
+        KeltnerChannelOutput {
+            average,
+            upper: average + band,
+            lower: average - band,
+        }
+    }
 }
 
 impl Period for KeltnerChannel {
     fn period(&self) -> usize {
-        self.period
+        self.average.period()
     }
 }
 
@@ -88,14 +151,11 @@ impl Next<f64> for KeltnerChannel {
     type Output = KeltnerChannelOutput;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        let atr = self.atr.next(input);
-        let average = self.ema.next(input);
-
-        Self::Output {
-            average,
-            upper: average + atr * self.multiplier,
-            lower: average - atr * self.multiplier,
-        }
+        let range = match self.method {
+            KeltnerChannelMethod::Atr => self.true_range.next(input),
+            KeltnerChannelMethod::Classic => 0.0,
+        };
+        self.output(input, range)
     }
 }
 
@@ -104,22 +164,19 @@ impl<T: Close + High + Low> Next<&T> for KeltnerChannel {
 
     fn next(&mut self, input: &T) -> Self::Output {
         let typical_price = (input.close() + input.high() + input.low()) / 3.0;
-
-        let average = self.ema.next(typical_price);
-        let atr = self.atr.next(input);
-
-        Self::Output {
-            average,
-            upper: average + atr * self.multiplier,
-            lower: average - atr * self.multiplier,
-        }
+        let range = match self.method {
+            KeltnerChannelMethod::Atr => self.true_range.next(input),
+            KeltnerChannelMethod::Classic => input.high() - input.low(),
+        };
+        self.output(typical_price, range)
     }
 }
 
 impl Reset for KeltnerChannel {
     fn reset(&mut self) {
-        self.atr.reset();
-        self.ema.reset();
+        self.true_range.reset();
+        self.average.reset();
+        self.band.reset();
     }
 }
 
@@ -131,7 +188,22 @@ impl Default for KeltnerChannel {
 
 impl fmt::Display for KeltnerChannel {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "KC({}, {})", self.period, self.multiplier)
+        let name = match self.method {
+            KeltnerChannelMethod::Atr => "KC",
+            KeltnerChannelMethod::Classic => "KC_CLASSIC",
+        };
+        if self.period() == self.atr_period() {
+            write!(f, "{}({}, {})", name, self.period(), self.multiplier)
+        } else {
+            write!(
+                f,
+                "{}({}, {}, {})",
+                name,
+                self.period(),
+                self.atr_period(),
+                self.multiplier
+            )
+        }
     }
 }
 
@@ -147,6 +219,8 @@ mod tests {
         assert!(KeltnerChannel::new(0, 2_f64).is_err());
         assert!(KeltnerChannel::new(1, 2_f64).is_ok());
         assert!(KeltnerChannel::new(2, 2_f64).is_ok());
+        assert!(KeltnerChannel::with_params(2, 0, 2_f64, KeltnerChannelMethod::Atr).is_err());
+        assert!(KeltnerChannel::with_params(0, 2, 2_f64, KeltnerChannelMethod::Classic).is_err());
     }
 
     #[test]
@@ -197,6 +271,42 @@ mod tests {
         assert_eq!(round(o3.upper), 2.525);
     }
 
+    #[test]
+    fn test_next_with_atr_period() {
+        let mut kc = KeltnerChannel::with_params(3, 1, 2.0, KeltnerChannelMethod::Atr).unwrap();
+
+        let dt1 = Bar::new().low(1.2).high(1.7).close(1.3);
+        let o1 = kc.next(&dt1);
+        assert_eq!(round(o1.average), 1.4);
+        assert_eq!(round(o1.upper), 2.4);
+
+        // ATR(1) is the true range of the last bar
+        let dt2 = Bar::new().low(1.3).high(2.3).close(1.4);
+        let o2 = kc.next(&dt2);
+        assert_eq!(round(o2.average), 1.533);
+        assert_eq!(round(o2.lower), -0.467);
+        assert_eq!(round(o2.upper), 3.533);
+    }
+
+    #[test]
+    fn test_next_classic() {
+        let mut kc = KeltnerChannel::with_params(2, 2, 1.0, KeltnerChannelMethod::Classic).unwrap();
+        assert_eq!(kc.method(), KeltnerChannelMethod::Classic);
+
+        let o1 = kc.next(&Bar::new().low(1.0).high(3.0).close(2.0));
+        assert_eq!((o1.average, o1.lower, o1.upper), (2.0, 0.0, 4.0));
+
+        let o2 = kc.next(&Bar::new().low(3.0).high(7.0).close(5.0));
+        assert_eq!((o2.average, o2.lower, o2.upper), (3.5, 0.5, 6.5));
+
+        let o3 = kc.next(&Bar::new().low(5.0).high(7.0).close(6.0));
+        assert_eq!((o3.average, o3.lower, o3.upper), (5.5, 2.5, 8.5));
+
+        // A single value has no range
+        let o4 = kc.next(9.0);
+        assert_eq!((o4.average, o4.lower, o4.upper), (7.5, 6.5, 8.5));
+    }
+
     #[test]
     fn test_reset() {
         let mut kc = KeltnerChannel::new(5, 2.0_f64).unwrap();
@@ -233,5 +343,11 @@ mod tests {
     fn test_display() {
         let kc = KeltnerChannel::new(10, 3.0_f64).unwrap();
         assert_eq!(format!("{}", kc), "KC(10, 3)");
+
+        let kc = KeltnerChannel::with_params(20, 10, 2.0, KeltnerChannelMethod::Atr).unwrap();
+        assert_eq!(format!("{}", kc), "KC(20, 10, 2)");
+
+        let kc = KeltnerChannel::with_params(10, 10, 1.0, KeltnerChannelMethod::Classic).unwrap();
+        assert_eq!(format!("{}", kc), "KC_CLASSIC(10, 1)");
     }
 }
@@ -0,0 +1,178 @@
+use crate::errors::Result;
+use crate::RollingWindow;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Lag buffer shared by the rate of change family of indicators.
+///
+/// Returns the input _n_ periods ago. Until there is one, the first input is returned.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) struct Lag {
+    window: RollingWindow,
+}
+
+impl Lag {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            window: RollingWindow::new(period)?,
+        })
+    }
+
+    pub fn period(&self) -> usize {
+        self.window.capacity()
+    }
+
+    pub fn next(&mut self, input: f64) -> f64 {
+        match self.window.push(input) {
+            Some(evicted) => evicted,
+            None => self.window.oldest().unwrap(),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+/// Defines an indicator computed from the close price and the close price _n_ periods ago.
+///
+/// Generates the struct over a [`Lag`] buffer, `new`, [`Period`](crate::Period),
+/// [`Next`](crate::Next) for `f64` and `&T: Close`, [`Default`], [`Display`](std::fmt::Display),
+/// [`Reset`](crate::Reset) and the tests shared by all of them. The formula is given as a closure
+/// over the current and the lagged price.
+macro_rules! lag_indicator {
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident;
+        display = $display:literal,
+        default_period = $default_period:literal,
+        formula = |$input:ident, $previous:ident| $formula:expr $(,)?
+    ) => {
+        $(#[$attr])*
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            lag: $crate::indicators::lag::Lag,
+        }
+
+        impl $name {
+            pub fn new(period: usize) -> $crate::errors::Result<Self> {
+                Ok(Self {
+                    lag: $crate::indicators::lag::Lag::new(period)?,
+                })
+            }
+        }
+
+        impl $crate::Period for $name {
+            fn period(&self) -> usize {
+                self.lag.period()
+            }
+        }
+
+        impl $crate::Next<f64> for $name {
+            type Output = f64;
+
+            fn next(&mut self, $input: f64) -> f64 {
+                let $previous = self.lag.next($input);
+                $formula
+            }
+        }
+
+        impl<T: $crate::Close> $crate::Next<&T> for $name {
+            type Output = f64;
+
+            fn next(&mut self, input: &T) -> f64 {
+                self.next(input.close())
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new($default_period).unwrap()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, concat!($display, "({})"), $crate::Period::period(self))
+            }
+        }
+
+        impl $crate::Reset for $name {
+            fn reset(&mut self) {
+                self.lag.reset();
+            }
+        }
+
+        #[cfg(test)]
+        mod lag_indicator_tests {
+            use super::*;
+            use crate::test_helper::*;
+            use crate::{Next, Period, Reset};
+
+            const INPUTS: [f64; 6] = [10.0, 10.4, 10.57, 10.8, 10.9, 10.0];
+
+            test_indicator!($name);
+
+            #[test]
+            fn test_new() {
+                assert!($name::new(0).is_err());
+                assert!($name::new(1).is_ok());
+                assert!($name::new(100_000).is_ok());
+                assert_eq!($name::new(3).unwrap().period(), 3);
+            }
+
+            #[test]
+            fn test_next_bar() {
+                let mut by_value = $name::new(3).unwrap();
+                let mut by_bar = $name::new(3).unwrap();
+
+                for &close in INPUTS.iter() {
+                    assert_eq!(by_bar.next(&Bar::new().close(close)), by_value.next(close));
+                }
+            }
+
+            #[test]
+            fn test_reset() {
+                let mut indicator = $name::new(3).unwrap();
+                let mut fresh = $name::new(3).unwrap();
+
+                indicator.next(12.3);
+                indicator.next(15.0);
+
+                indicator.reset();
+
+                for &input in INPUTS.iter() {
+                    assert_eq!(indicator.next(input), fresh.next(input));
+                }
+            }
+
+            #[test]
+            fn test_display() {
+                let indicator = $name::new(5).unwrap();
+                assert_eq!(format!("{}", indicator), concat!($display, "(5)"));
+            }
+        }
+    };
+}
+
+pub(crate) use lag_indicator;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lag() {
+        let mut lag = Lag::new(2).unwrap();
+
+        assert_eq!(lag.next(1.0), 1.0);
+        assert_eq!(lag.next(2.0), 1.0);
+        assert_eq!(lag.next(3.0), 1.0);
+        assert_eq!(lag.next(4.0), 2.0);
+
+        lag.reset();
+        assert_eq!(lag.next(5.0), 5.0);
+    }
+}
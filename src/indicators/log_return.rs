@@ -0,0 +1,78 @@
+use crate::indicators::lag::lag_indicator;
+
+lag_indicator! {
+    /// Logarithmic Return (LOGRET)
+    ///
+    /// Natural logarithm of the ratio of the price to the price _n_ periods ago.
+    /// Unlike percentage changes, log returns over consecutive periods add up.
+    ///
+    /// # Formula
+    ///
+    /// LOGRET = ln(P<sub>t</sub> / P<sub>t-n</sub>)
+    ///
+    /// Where:
+    ///
+    /// * P<sub>t</sub> - price at the moment
+    /// * P<sub>t-n</sub> - price _n_ periods ago
+    ///
+    /// # Parameters
+    ///
+    /// * _period_ - number of periods integer greater than 0
+    ///
+    /// Prices are expected to be positive: when either price is not, the return is undefined and 0 is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ta::indicators::LogReturn;
+    /// use ta::Next;
+    ///
+    /// let mut ret = LogReturn::new(2).unwrap();
+    /// assert_eq!(ret.next(10.0), 0.0);
+    /// assert_eq!(ret.next(20.0), 2.0_f64.ln());
+    /// assert_eq!(ret.next(5.0), 0.5_f64.ln());
+    /// ```
+    ///
+    /// # Links
+    ///
+    /// * [Rate of return, Wikipedia](https://en.wikipedia.org/wiki/Rate_of_return#Logarithmic_or_continuously_compounded_return)
+    ///
+    #[doc(alias = "LOGRET")]
+    pub struct LogReturn;
+    display = "LOGRET",
+    default_period = 1,
+    formula = |input, previous| if previous <= 0.0 || input <= 0.0 {
+        0.0
+    } else {
+        (input / previous).ln()
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::traits::Next;
+
+    #[test]
+    fn test_next_f64() {
+        let mut ret = LogReturn::new(3).unwrap();
+
+        assert_eq!(round(ret.next(10.0)), 0.0);
+        assert_eq!(round(ret.next(10.4)), 0.039);
+        assert_eq!(round(ret.next(10.57)), 0.055);
+        assert_eq!(round(ret.next(10.8)), 0.077);
+        assert_eq!(round(ret.next(10.9)), 0.047);
+        assert_eq!(round(ret.next(10.0)), -0.055);
+    }
+
+    #[test]
+    fn test_next_zero() {
+        let mut ret = LogReturn::new(1).unwrap();
+
+        assert_eq!(ret.next(0.0), 0.0);
+        assert_eq!(ret.next(5.0), 0.0);
+        assert_eq!(ret.next(-5.0), 0.0);
+        assert_eq!(ret.next(5.0), 0.0);
+    }
+}
@@ -1,12 +1,19 @@
 use std::fmt;
 
-use crate::errors::{Result, TaError};
+use crate::errors::Result;
+#[cfg(feature = "serde")]
+use crate::errors::TaError;
+use crate::storage::{Heap, Inline, Storage};
+use crate::RollingWindow;
 use crate::{High, Next, Period, Reset};
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{de::Error, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Returns the highest value in a given time frame.
 ///
+/// The candidates for the highest value are kept in a monotonic deque,
+/// so every update takes amortized constant time regardless of the period.
+///
 /// # Parameters
 ///
 /// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
@@ -24,72 +31,113 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(max.next(4.0), 5.0);
 /// assert_eq!(max.next(8.0), 8.0);
 /// ```
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub type Maximum = MaximumBase<Heap>;
+
+/// Returns the highest value in a time frame of `N` values known at compile time.
+///
+/// Behaves exactly like [Maximum], but the window is stored inline
+/// and no heap allocation is performed.
+pub type InlineMaximum<const N: usize> = MaximumBase<Inline<N>>;
+
+/// Returns the highest value in a given time frame, generic over its [Storage].
+///
+/// See [Maximum] and [InlineMaximum].
 #[derive(Debug, Clone)]
-pub struct Maximum {
-    period: usize,
-    max_index: usize,
-    cur_index: usize,
-    deque: Box<[f64]>,
+pub struct MaximumBase<S: Storage> {
+    count: usize,
+    head: usize,
+    len: usize,
+    window: RollingWindow<S>,
+    deque: S::Buffer<usize>,
 }
 
-impl Maximum {
-    pub fn new(period: usize) -> Result<Self> {
-        match period {
-            0 => Err(TaError::InvalidParameter),
-            _ => Ok(Self {
-                period,
-                max_index: 0,
-                cur_index: 0,
-                deque: vec![f64::NEG_INFINITY; period].into_boxed_slice(),
-            }),
-        }
+impl<S: Storage> MaximumBase<S> {
+    fn with_period(period: usize) -> Result<Self> {
+        Ok(Self {
+            count: 0,
+            head: 0,
+            len: 0,
+            window: RollingWindow::new(period)?,
+            deque: S::buffer(period, 0),
+        })
+    }
+
+    /// Returns the value at the given position, it must be in the current time frame.
+    fn value(&self, index: usize) -> f64 {
+        self.window
+            .get(index + self.window.len() - self.count)
+            .unwrap()
     }
 
-    fn find_max_index(&self) -> usize {
-        let mut max = f64::NEG_INFINITY;
-        let mut index: usize = 0;
+    /// Returns the number of bars since the highest value of the current time frame.
+    ///
+    /// `0` means that the last input is the highest value. When there are several
+    /// highest values in the time frame, the most recent one is taken.
+    pub fn age(&self) -> usize {
+        self.count.saturating_sub(1) - self.index()
+    }
 
-        for (i, &val) in self.deque.iter().enumerate() {
-            if val > max {
-                max = val;
-                index = i;
-            }
+    /// Returns the position of the highest value of the current time frame,
+    /// counted from the first input after creation or reset.
+    pub fn index(&self) -> usize {
+        if self.len == 0 {
+            0
+        } else {
+            S::get(&self.deque, self.head)
         }
+    }
+}
 
-        index
+impl Maximum {
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_period(period)
     }
 }
 
-impl Period for Maximum {
+impl<const N: usize> InlineMaximum<N> {
+    pub fn new() -> Result<Self> {
+        Self::with_period(N)
+    }
+}
+
+impl<S: Storage> Period for MaximumBase<S> {
     fn period(&self) -> usize {
-        self.period
+        self.window.capacity()
     }
 }
 
-impl Next<f64> for Maximum {
+impl<S: Storage> Next<f64> for MaximumBase<S> {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        self.deque[self.cur_index] = input;
+        let period = self.period();
+        let index = self.count;
+        self.window.push(input);
+        self.count += 1;
 
-        if input > self.deque[self.max_index] {
-            self.max_index = self.cur_index;
-        } else if self.max_index == self.cur_index {
-            self.max_index = self.find_max_index();
+        // The front candidate leaves the time frame
+        if self.len > 0 && S::get(&self.deque, self.head) + period <= index {
+            self.head = (self.head + 1) % period;
+            self.len -= 1;
         }
 
-        self.cur_index = if self.cur_index + 1 < self.period {
-            self.cur_index + 1
-        } else {
-            0
-        };
+        // Candidates that are not greater than the input can never be the highest value again
+        while self.len > 0 {
+            let back = S::get(&self.deque, self.head + self.len - 1);
+            if self.value(back) > input {
+                break;
+            }
+            self.len -= 1;
+        }
+
+        S::set(&mut self.deque, self.head + self.len, index);
+        self.len += 1;
 
-        self.deque[self.max_index]
+        self.value(self.index())
     }
 }
 
-impl<T: High> Next<&T> for Maximum {
+impl<S: Storage, T: High> Next<&T> for MaximumBase<S> {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
@@ -97,11 +145,59 @@ impl<T: High> Next<&T> for Maximum {
     }
 }
 
-impl Reset for Maximum {
+impl<S: Storage> Reset for MaximumBase<S> {
     fn reset(&mut self) {
-        for i in 0..self.period {
-            self.deque[i] = f64::NEG_INFINITY;
+        self.count = 0;
+        self.head = 0;
+        self.len = 0;
+        self.window.clear();
+    }
+}
+
+// Only the window is serialized, the candidates are rebuilt from it on deserialization.
+// This works with any storage, including inline arrays of arbitrary size,
+// and can not produce candidates outside of the window.
+#[cfg(feature = "serde")]
+impl<S: Storage> Serialize for MaximumBase<S> {
+    fn serialize<Ser: Serializer>(
+        &self,
+        serializer: Ser,
+    ) -> std::result::Result<Ser::Ok, Ser::Error> {
+        let mut state = serializer.serialize_struct("Maximum", 2)?;
+        state.serialize_field("count", &self.count)?;
+        state.serialize_field("window", &self.window)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(bound = "")]
+struct RawMaximum<S: Storage> {
+    count: usize,
+    window: RollingWindow<S>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: Storage> Deserialize<'de> for MaximumBase<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let raw = RawMaximum::<S>::deserialize(deserializer)?;
+        if raw.count < raw.window.len() {
+            return Err(D::Error::custom(TaError::DataItemInvalid));
         }
+
+        let mut indicator = Self::with_period(raw.window.capacity()).map_err(D::Error::custom)?;
+        for value in raw.window.iter() {
+            indicator.next(value);
+        }
+
+        // Positions are counted from the first input, not from the oldest value of the window
+        let offset = raw.count - raw.window.len();
+        for index in indicator.deque.as_mut() {
+            *index += offset;
+        }
+        indicator.count = raw.count;
+        Ok(indicator)
     }
 }
 
@@ -111,9 +207,15 @@ impl Default for Maximum {
     }
 }
 
-impl fmt::Display for Maximum {
+impl<const N: usize> Default for InlineMaximum<N> {
+    fn default() -> Self {
+        Self::new().unwrap()
+    }
+}
+
+impl<S: Storage> fmt::Display for MaximumBase<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "MAX({})", self.period)
+        write!(f, "MAX({})", self.period())
     }
 }
 
@@ -121,28 +223,42 @@ impl fmt::Display for Maximum {
 mod tests {
     use super::*;
     use crate::test_helper::*;
+    use rand::Rng;
 
     test_indicator!(Maximum);
 
+    mod inline {
+        use super::*;
+
+        type InlineMax = InlineMaximum<14>;
+
+        test_indicator!(InlineMax);
+    }
+
     #[test]
     fn test_new() {
         assert!(Maximum::new(0).is_err());
         assert!(Maximum::new(1).is_ok());
+        assert!(InlineMaximum::<0>::new().is_err());
+        assert!(InlineMaximum::<1>::new().is_ok());
     }
 
     #[test]
     fn test_next() {
-        let mut max = Maximum::new(3).unwrap();
+        fn check<S: Storage>(mut max: MaximumBase<S>) {
+            assert_eq!(max.next(4.0), 4.0);
+            assert_eq!(max.next(1.2), 4.0);
+            assert_eq!(max.next(5.0), 5.0);
+            assert_eq!(max.next(3.0), 5.0);
+            assert_eq!(max.next(4.0), 5.0);
+            assert_eq!(max.next(0.0), 4.0);
+            assert_eq!(max.next(-1.0), 4.0);
+            assert_eq!(max.next(-2.0), 0.0);
+            assert_eq!(max.next(-1.5), -1.0);
+        }
 
-        assert_eq!(max.next(4.0), 4.0);
-        assert_eq!(max.next(1.2), 4.0);
-        assert_eq!(max.next(5.0), 5.0);
-        assert_eq!(max.next(3.0), 5.0);
-        assert_eq!(max.next(4.0), 5.0);
-        assert_eq!(max.next(0.0), 4.0);
-        assert_eq!(max.next(-1.0), 4.0);
-        assert_eq!(max.next(-2.0), 0.0);
-        assert_eq!(max.next(-1.5), -1.0);
+        check(Maximum::new(3).unwrap());
+        check(InlineMaximum::<3>::new().unwrap());
     }
 
     #[test]
@@ -151,33 +267,100 @@ mod tests {
             Bar::new().high(high)
         }
 
-        let mut max = Maximum::new(2).unwrap();
+        fn check<S: Storage>(mut max: MaximumBase<S>) {
+            assert_eq!(max.next(&bar(1.1)), 1.1);
+            assert_eq!(max.next(&bar(4.0)), 4.0);
+            assert_eq!(max.next(&bar(3.5)), 4.0);
+            assert_eq!(max.next(&bar(2.0)), 3.5);
+        }
 
-        assert_eq!(max.next(&bar(1.1)), 1.1);
-        assert_eq!(max.next(&bar(4.0)), 4.0);
-        assert_eq!(max.next(&bar(3.5)), 4.0);
-        assert_eq!(max.next(&bar(2.0)), 3.5);
+        check(Maximum::new(2).unwrap());
+        check(InlineMaximum::<2>::new().unwrap());
     }
 
     #[test]
-    fn test_reset() {
-        let mut max = Maximum::new(100).unwrap();
-        assert_eq!(max.next(4.0), 4.0);
-        assert_eq!(max.next(10.0), 10.0);
-        assert_eq!(max.next(4.0), 10.0);
+    fn test_next_matches_naive() {
+        let mut rng = rand::thread_rng();
+        let inputs: Vec<f64> = (0..500)
+            .map(|_| rng.gen_range(0.0, 10.0_f64).round())
+            .collect();
+
+        for &period in &[1, 2, 3, 7, 50] {
+            let mut max = Maximum::new(period).unwrap();
+
+            for (i, &input) in inputs.iter().enumerate() {
+                let window = &inputs[(i + 1).saturating_sub(period)..=i];
+                let expected = window.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                assert_eq!(max.next(input), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_descending() {
+        let mut max = Maximum::new(252).unwrap();
+
+        for i in (0..1000).rev() {
+            let input = i as f64;
+            let expected = if i <= 748 { input + 251.0 } else { 999.0 };
+            assert_eq!(max.next(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_age_and_index() {
+        let mut max = Maximum::new(3).unwrap();
+        assert_eq!((max.age(), max.index()), (0, 0));
+
+        max.next(4.0);
+        assert_eq!((max.age(), max.index()), (0, 0));
+        max.next(1.2);
+        assert_eq!((max.age(), max.index()), (1, 0));
+        max.next(5.0);
+        assert_eq!((max.age(), max.index()), (0, 2));
+        max.next(3.0);
+        assert_eq!((max.age(), max.index()), (1, 2));
+        max.next(5.0);
+        assert_eq!((max.age(), max.index()), (0, 4));
+        max.next(0.0);
+        assert_eq!((max.age(), max.index()), (1, 4));
+        max.next(-1.0);
+        assert_eq!((max.age(), max.index()), (2, 4));
+        max.next(-2.0);
+        assert_eq!((max.age(), max.index()), (2, 5));
 
         max.reset();
-        assert_eq!(max.next(4.0), 4.0);
+        max.next(1.0);
+        assert_eq!((max.age(), max.index()), (0, 0));
+    }
+
+    #[test]
+    fn test_reset() {
+        fn check<S: Storage>(mut max: MaximumBase<S>) {
+            assert_eq!(max.next(4.0), 4.0);
+            assert_eq!(max.next(10.0), 10.0);
+            assert_eq!(max.next(4.0), 10.0);
+
+            max.reset();
+            assert_eq!(max.next(4.0), 4.0);
+        }
+
+        check(Maximum::new(100).unwrap());
+        check(InlineMaximum::<100>::new().unwrap());
     }
 
     #[test]
     fn test_default() {
         Maximum::default();
+        InlineMaximum::<14>::default();
     }
 
     #[test]
     fn test_display() {
         let indicator = Maximum::new(7).unwrap();
         assert_eq!(format!("{}", indicator), "MAX(7)");
+
+        let indicator = InlineMaximum::<7>::new().unwrap();
+        assert_eq!(format!("{}", indicator), "MAX(7)");
     }
 }
@@ -0,0 +1,197 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// McGinley Dynamic.
+///
+/// Developed by John R. McGinley, an adaptive moving average that speeds up when prices
+/// fall below it and slows down when prices rise above it, so it follows prices more
+/// closely than a moving average of the same period and rarely gets whipsawed.
+///
+/// # Formula
+///
+/// MD<sub>t</sub> = MD<sub>t-1</sub> + α<sub>t</sub> * (p<sub>t</sub> - MD<sub>t-1</sub>)
+///
+/// α<sub>t</sub> = 1 / (_period_ * (p<sub>t</sub> / MD<sub>t-1</sub>)<sup>4</sup>)
+///
+/// Where:
+///
+/// * _p<sub>t</sub>_ - input value at a time period t
+///
+/// The first input is taken as is, and _α_ is capped at 1.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::McGinleyDynamic;
+/// use ta::Next;
+///
+/// let mut md = McGinleyDynamic::new(2).unwrap();
+/// assert_eq!(md.next(10.0), 10.0);
+/// assert_eq!(md.next(10.0), 10.0);
+/// assert_eq!(md.alpha(), Some(0.5));
+/// ```
+///
+/// # Links
+///
+/// * [McGinley Dynamic, Investopedia](https://www.investopedia.com/articles/forex/09/mcginley-dynamic-indicator.asp)
+///
+#[doc(alias = "MD")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct McGinleyDynamic {
+    period: usize,
+    current: f64,
+    alpha: Option<f64>,
+}
+
+impl McGinleyDynamic {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                current: 0.0,
+                alpha: None,
+            }),
+        }
+    }
+
+    /// Returns the smoothing factor applied to the last input, `None` before the first input.
+    pub fn alpha(&self) -> Option<f64> {
+        self.alpha
+    }
+}
+
+impl Period for McGinleyDynamic {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for McGinleyDynamic {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let alpha = if self.alpha.is_none() || self.current == 0.0 {
+            1.0
+        } else {
+            let ratio = input / self.current;
+            (1.0 / (self.period as f64 * ratio.powi(4))).min(1.0)
+        };
+
+        self.alpha = Some(alpha);
+        self.current += alpha * (input - self.current);
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for McGinleyDynamic {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for McGinleyDynamic {
+    fn reset(&mut self) {
+        self.current = 0.0;
+        self.alpha = None;
+    }
+}
+
+impl Default for McGinleyDynamic {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for McGinleyDynamic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MD({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(McGinleyDynamic);
+
+    #[test]
+    fn test_new() {
+        assert!(McGinleyDynamic::new(0).is_err());
+        assert!(McGinleyDynamic::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut md = McGinleyDynamic::new(3).unwrap();
+        assert_eq!(md.alpha(), None);
+
+        let test_data = vec![
+            // input, md, alpha
+            (10.0, 10.0, 1.0),
+            (11.0, 10.228, 0.228),
+            (12.0, 10.539, 0.176),
+            (11.0, 10.669, 0.281),
+            (9.0, 9.57, 0.658),
+            (9.0, 9.327, 0.426),
+        ];
+
+        for (input, value, alpha) in test_data {
+            assert_eq!(round(md.next(input)), value);
+            assert_eq!(md.alpha().map(round), Some(alpha));
+        }
+    }
+
+    #[test]
+    fn test_next_alpha_is_capped() {
+        let mut md = McGinleyDynamic::new(2).unwrap();
+
+        assert_eq!(md.next(10.0), 10.0);
+        assert_eq!(md.next(1.0), 1.0);
+        assert_eq!(md.alpha(), Some(1.0));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut md = McGinleyDynamic::new(3).unwrap();
+
+        assert_eq!(md.next(&Bar::new().close(10)), 10.0);
+        assert_eq!(round(md.next(&Bar::new().close(11))), 10.228);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut md = McGinleyDynamic::new(3).unwrap();
+
+        md.next(10.0);
+        md.next(11.0);
+
+        md.reset();
+        assert_eq!(md.alpha(), None);
+        assert_eq!(md.next(11.0), 11.0);
+        assert_eq!(md.alpha(), Some(1.0));
+    }
+
+    #[test]
+    fn test_default() {
+        McGinleyDynamic::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let md = McGinleyDynamic::new(14).unwrap();
+        assert_eq!(format!("{}", md), "MD(14)");
+    }
+}
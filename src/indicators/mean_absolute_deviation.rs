@@ -3,8 +3,9 @@ use std::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::errors::Result;
+use crate::storage::{Heap, Inline, Storage};
+use crate::{Close, Next, Period, Reset, RollingWindow};
 
 /// Mean Absolute Deviation (MAD)
 ///
@@ -27,66 +28,70 @@ use crate::{Close, Next, Period, Reset};
 ///
 /// * [Mean Absolute Deviation, Wikipedia](https://en.wikipedia.org/wiki/Mean_absolute_deviation)
 ///
+pub type MeanAbsoluteDeviation = MeanAbsoluteDeviationBase<Heap>;
+
+/// Mean Absolute Deviation (MAD) with the period `N` known at compile time.
+///
+/// Behaves exactly like [MeanAbsoluteDeviation], but the window is stored inline
+/// and no heap allocation is performed.
+pub type InlineMeanAbsoluteDeviation<const N: usize> = MeanAbsoluteDeviationBase<Inline<N>>;
+
+/// Mean Absolute Deviation (MAD) generic over its [Storage].
+///
+/// See [MeanAbsoluteDeviation] and [InlineMeanAbsoluteDeviation].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct MeanAbsoluteDeviation {
-    period: usize,
-    index: usize,
-    count: usize,
+pub struct MeanAbsoluteDeviationBase<S: Storage> {
     sum: f64,
-    deque: Box<[f64]>,
+    window: RollingWindow<S>,
+}
+
+impl<S: Storage> MeanAbsoluteDeviationBase<S> {
+    fn with_period(period: usize) -> Result<Self> {
+        Ok(Self {
+            sum: 0.0,
+            window: RollingWindow::new(period)?,
+        })
+    }
 }
 
 impl MeanAbsoluteDeviation {
     pub fn new(period: usize) -> Result<Self> {
-        match period {
-            0 => Err(TaError::InvalidParameter),
-            _ => Ok(Self {
-                period,
-                index: 0,
-                count: 0,
-                sum: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
-            }),
-        }
+        Self::with_period(period)
+    }
+}
+
+impl<const N: usize> InlineMeanAbsoluteDeviation<N> {
+    pub fn new() -> Result<Self> {
+        Self::with_period(N)
     }
 }
 
-impl Period for MeanAbsoluteDeviation {
+impl<S: Storage> Period for MeanAbsoluteDeviationBase<S> {
     fn period(&self) -> usize {
-        self.period
+        self.window.capacity()
     }
 }
 
-impl Next<f64> for MeanAbsoluteDeviation {
+impl<S: Storage> Next<f64> for MeanAbsoluteDeviationBase<S> {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        self.sum = if self.count < self.period {
-            self.count = self.count + 1;
-            self.sum + input
-        } else {
-            self.sum + input - self.deque[self.index]
-        };
-
-        self.deque[self.index] = input;
-        self.index = if self.index + 1 < self.period {
-            self.index + 1
-        } else {
-            0
-        };
-
-        let mean = self.sum / self.count as f64;
+        let old_val = self.window.push(input).unwrap_or(0.0);
+        self.sum = self.sum + input - old_val;
+
+        let count = self.window.len() as f64;
+        let mean = self.sum / count;
 
         let mut mad = 0.0;
-        for value in &self.deque[..self.count] {
+        for value in self.window.iter() {
             mad += (value - mean).abs();
         }
-        mad / self.count as f64
+        mad / count
     }
 }
 
-impl<T: Close> Next<&T> for MeanAbsoluteDeviation {
+impl<S: Storage, T: Close> Next<&T> for MeanAbsoluteDeviationBase<S> {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
@@ -94,14 +99,10 @@ impl<T: Close> Next<&T> for MeanAbsoluteDeviation {
     }
 }
 
-impl Reset for MeanAbsoluteDeviation {
+impl<S: Storage> Reset for MeanAbsoluteDeviationBase<S> {
     fn reset(&mut self) {
-        self.index = 0;
-        self.count = 0;
         self.sum = 0.0;
-        for i in 0..self.period {
-            self.deque[i] = 0.0;
-        }
+        self.window.clear();
     }
 }
 
@@ -111,9 +112,15 @@ impl Default for MeanAbsoluteDeviation {
     }
 }
 
-impl fmt::Display for MeanAbsoluteDeviation {
+impl<const N: usize> Default for InlineMeanAbsoluteDeviation<N> {
+    fn default() -> Self {
+        Self::new().unwrap()
+    }
+}
+
+impl<S: Storage> fmt::Display for MeanAbsoluteDeviationBase<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "MAD({})", self.period)
+        write!(f, "MAD({})", self.period())
     }
 }
 
@@ -124,45 +131,65 @@ mod tests {
 
     test_indicator!(MeanAbsoluteDeviation);
 
+    mod inline {
+        use super::*;
+
+        type InlineMad = InlineMeanAbsoluteDeviation<9>;
+
+        test_indicator!(InlineMad);
+    }
+
     #[test]
     fn test_new() {
         assert!(MeanAbsoluteDeviation::new(0).is_err());
         assert!(MeanAbsoluteDeviation::new(1).is_ok());
+        assert!(InlineMeanAbsoluteDeviation::<0>::new().is_err());
+        assert!(InlineMeanAbsoluteDeviation::<1>::new().is_ok());
     }
 
     #[test]
     fn test_next() {
-        let mut mad = MeanAbsoluteDeviation::new(5).unwrap();
+        fn check<S: Storage>(mut mad: MeanAbsoluteDeviationBase<S>) {
+            assert_eq!(round(mad.next(1.5)), 0.0);
+            assert_eq!(round(mad.next(4.0)), 1.25);
+            assert_eq!(round(mad.next(8.0)), 2.333);
+            assert_eq!(round(mad.next(4.0)), 1.813);
+            assert_eq!(round(mad.next(4.0)), 1.48);
+            assert_eq!(round(mad.next(1.5)), 1.48);
+        }
 
-        assert_eq!(round(mad.next(1.5)), 0.0);
-        assert_eq!(round(mad.next(4.0)), 1.25);
-        assert_eq!(round(mad.next(8.0)), 2.333);
-        assert_eq!(round(mad.next(4.0)), 1.813);
-        assert_eq!(round(mad.next(4.0)), 1.48);
-        assert_eq!(round(mad.next(1.5)), 1.48);
+        check(MeanAbsoluteDeviation::new(5).unwrap());
+        check(InlineMeanAbsoluteDeviation::<5>::new().unwrap());
     }
 
     #[test]
     fn test_reset() {
-        let mut mad = MeanAbsoluteDeviation::new(5).unwrap();
+        fn check<S: Storage>(mut mad: MeanAbsoluteDeviationBase<S>) {
+            assert_eq!(round(mad.next(1.5)), 0.0);
+            assert_eq!(round(mad.next(4.0)), 1.25);
 
-        assert_eq!(round(mad.next(1.5)), 0.0);
-        assert_eq!(round(mad.next(4.0)), 1.25);
+            mad.reset();
 
-        mad.reset();
+            assert_eq!(round(mad.next(1.5)), 0.0);
+            assert_eq!(round(mad.next(4.0)), 1.25);
+        }
 
-        assert_eq!(round(mad.next(1.5)), 0.0);
-        assert_eq!(round(mad.next(4.0)), 1.25);
+        check(MeanAbsoluteDeviation::new(5).unwrap());
+        check(InlineMeanAbsoluteDeviation::<5>::new().unwrap());
     }
 
     #[test]
     fn test_default() {
         MeanAbsoluteDeviation::default();
+        InlineMeanAbsoluteDeviation::<9>::default();
     }
 
     #[test]
     fn test_display() {
         let indicator = MeanAbsoluteDeviation::new(10).unwrap();
         assert_eq!(format!("{}", indicator), "MAD(10)");
+
+        let indicator = InlineMeanAbsoluteDeviation::<10>::new().unwrap();
+        assert_eq!(format!("{}", indicator), "MAD(10)");
     }
 }
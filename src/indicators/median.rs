@@ -0,0 +1,302 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::storage::{Heap, Inline, Storage};
+use crate::{Close, Next, Period, Reset, RollingWindow};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Rolling median.
+///
+/// Returns the middle value of the last _period_ values, or the mean of the two
+/// middle values when their number is even. Unlike the
+/// [SMA](crate::indicators::SimpleMovingAverage), the median is not pulled by outliers.
+///
+/// Besides the window, the values are kept sorted, so every update takes a binary search
+/// and a shift of the sorted buffer instead of sorting the whole window.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Median;
+/// use ta::Next;
+///
+/// let mut median = Median::new(3).unwrap();
+/// assert_eq!(median.next(3.0), 3.0);
+/// assert_eq!(median.next(1.0), 2.0);
+/// assert_eq!(median.next(2.0), 2.0);
+/// assert_eq!(median.next(100.0), 2.0);
+/// ```
+///
+/// # Links
+///
+/// * [Median, Wikipedia](https://en.wikipedia.org/wiki/Median)
+///
+pub type Median = MedianBase<Heap>;
+
+/// Rolling median of the last `N` values, with `N` known at compile time.
+///
+/// Behaves exactly like [Median], but the window is stored inline
+/// and no heap allocation is performed.
+pub type InlineMedian<const N: usize> = MedianBase<Inline<N>>;
+
+/// Rolling median generic over its [Storage].
+///
+/// See [Median] and [InlineMedian].
+#[derive(Debug, Clone)]
+pub struct MedianBase<S: Storage> {
+    window: RollingWindow<S>,
+    sorted: S::Buffer<f64>,
+}
+
+impl<S: Storage> MedianBase<S> {
+    pub(super) fn with_period(period: usize) -> Result<Self> {
+        Ok(Self {
+            window: RollingWindow::new(period)?,
+            sorted: S::buffer(period, 0.0),
+        })
+    }
+
+    /// Returns the values of the current time frame in ascending order.
+    pub(super) fn sorted(&self) -> &[f64] {
+        &self.sorted.as_ref()[..self.window.len()]
+    }
+
+    /// Returns the median of the current time frame, 0 if it is empty.
+    pub(super) fn median(&self) -> f64 {
+        let sorted = self.sorted();
+        let len = sorted.len();
+        if len == 0 {
+            0.0
+        } else if len % 2 == 1 {
+            sorted[len / 2]
+        } else {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2.0
+        }
+    }
+}
+
+impl Median {
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_period(period)
+    }
+}
+
+impl<const N: usize> InlineMedian<N> {
+    pub fn new() -> Result<Self> {
+        Self::with_period(N)
+    }
+}
+
+impl<S: Storage> Period for MedianBase<S> {
+    fn period(&self) -> usize {
+        self.window.capacity()
+    }
+}
+
+impl<S: Storage> Next<f64> for MedianBase<S> {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let mut len = self.window.len();
+        let old_val = self.window.push(input);
+        let sorted = self.sorted.as_mut();
+
+        if let Some(old_val) = old_val {
+            let index = sorted[..len].partition_point(|value| value.total_cmp(&old_val).is_lt());
+            sorted.copy_within(index + 1..len, index);
+            len -= 1;
+        }
+
+        let index = sorted[..len].partition_point(|value| value.total_cmp(&input).is_lt());
+        sorted.copy_within(index..len, index + 1);
+        sorted[index] = input;
+
+        self.median()
+    }
+}
+
+impl<S: Storage, T: Close> Next<&T> for MedianBase<S> {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl<S: Storage> Reset for MedianBase<S> {
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+// Only the window is serialized, the sorted values are rebuilt from it on deserialization.
+// This works with any storage, including inline arrays of arbitrary size,
+// and can not produce sorted values that differ from the window.
+#[cfg(feature = "serde")]
+impl<S: Storage> Serialize for MedianBase<S> {
+    fn serialize<Ser: Serializer>(
+        &self,
+        serializer: Ser,
+    ) -> std::result::Result<Ser::Ok, Ser::Error> {
+        self.window.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: Storage> Deserialize<'de> for MedianBase<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let window = RollingWindow::<S>::deserialize(deserializer)?;
+        let mut sorted = S::buffer(window.capacity(), 0.0);
+        for (value, input) in sorted.as_mut().iter_mut().zip(window.iter()) {
+            *value = input;
+        }
+        sorted.as_mut()[..window.len()].sort_by(|a, b| a.total_cmp(b));
+
+        Ok(Self { window, sorted })
+    }
+}
+
+impl Default for Median {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl<const N: usize> Default for InlineMedian<N> {
+    fn default() -> Self {
+        Self::new().unwrap()
+    }
+}
+
+impl<S: Storage> fmt::Display for MedianBase<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MEDIAN({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use rand::Rng;
+
+    test_indicator!(Median);
+
+    mod inline {
+        use super::*;
+
+        type InlineMed = InlineMedian<9>;
+
+        test_indicator!(InlineMed);
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Median::new(0).is_err());
+        assert!(Median::new(1).is_ok());
+        assert!(InlineMedian::<0>::new().is_err());
+        assert!(InlineMedian::<1>::new().is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        fn check<S: Storage>(mut median: MedianBase<S>) {
+            assert_eq!(median.next(3.0), 3.0);
+            assert_eq!(median.next(1.0), 2.0);
+            assert_eq!(median.next(2.0), 2.0);
+            assert_eq!(median.next(5.0), 2.0);
+            assert_eq!(median.next(4.0), 4.0);
+            assert_eq!(median.next(4.0), 4.0);
+            assert_eq!(median.next(-1.0), 4.0);
+            assert_eq!(median.next(0.0), 0.0);
+        }
+
+        check(Median::new(3).unwrap());
+        check(InlineMedian::<3>::new().unwrap());
+    }
+
+    #[test]
+    fn test_next_with_duplicates() {
+        let mut median = Median::new(4).unwrap();
+
+        assert_eq!(median.next(1.0), 1.0);
+        assert_eq!(median.next(1.0), 1.0);
+        assert_eq!(median.next(2.0), 1.0);
+        assert_eq!(median.next(1.0), 1.0);
+        assert_eq!(median.next(2.0), 1.5);
+        assert_eq!(median.next(2.0), 2.0);
+        assert_eq!(median.next(2.0), 2.0);
+    }
+
+    #[test]
+    fn test_next_against_sorting() {
+        let mut rng = rand::thread_rng();
+        let mut median = Median::new(7).unwrap();
+        let mut inputs = Vec::new();
+
+        for _ in 0..1_000 {
+            let input = rng.gen_range(0, 20) as f64;
+            inputs.push(input);
+
+            let start = inputs.len().saturating_sub(7);
+            let mut window = inputs[start..].to_vec();
+            window.sort_by(|a, b| a.total_cmp(b));
+            let len = window.len();
+            let expected = if len % 2 == 1 {
+                window[len / 2]
+            } else {
+                (window[len / 2 - 1] + window[len / 2]) / 2.0
+            };
+
+            assert_eq!(median.next(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut median = Median::new(2).unwrap();
+        assert_eq!(median.next(&bar(10.0)), 10.0);
+        assert_eq!(median.next(&bar(20.0)), 15.0);
+        assert_eq!(median.next(&bar(40.0)), 30.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        fn check<S: Storage>(mut median: MedianBase<S>) {
+            assert_eq!(median.next(3.0), 3.0);
+            assert_eq!(median.next(1.0), 2.0);
+
+            median.reset();
+
+            assert_eq!(median.next(5.0), 5.0);
+            assert_eq!(median.next(1.0), 3.0);
+        }
+
+        check(Median::new(3).unwrap());
+        check(InlineMedian::<3>::new().unwrap());
+    }
+
+    #[test]
+    fn test_default() {
+        Median::default();
+        InlineMedian::<9>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Median::new(10).unwrap();
+        assert_eq!(format!("{}", indicator), "MEDIAN(10)");
+
+        let indicator = InlineMedian::<10>::new().unwrap();
+        assert_eq!(format!("{}", indicator), "MEDIAN(10)");
+    }
+}
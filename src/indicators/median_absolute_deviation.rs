@@ -0,0 +1,281 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::MedianBase;
+use crate::storage::{Heap, Inline, Storage};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Median Absolute Deviation (MedAD).
+///
+/// The median of the absolute deviations of the last _period_ values from their
+/// [median](crate::indicators::Median). It is a robust measure of dispersion:
+/// unlike the [standard deviation](crate::indicators::StandardDeviation) or the
+/// [mean absolute deviation](crate::indicators::MeanAbsoluteDeviation),
+/// a few outliers hardly affect it.
+///
+/// # Formula
+///
+/// MedAD(_period_) = MEDIAN(|x<sub>1</sub> - MEDIAN(_period_)|, ..., |x<sub>_period_</sub> - MEDIAN(_period_)|)
+///
+/// To estimate the standard deviation of normally distributed values, multiply the
+/// result by 1.4826.
+///
+/// The values are kept sorted, so the absolute deviations form two sorted sequences on
+/// both sides of the median: their median is found by merging the sequences up to the
+/// middle, without sorting the deviations.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::MedianAbsoluteDeviation;
+/// use ta::Next;
+///
+/// let mut mad = MedianAbsoluteDeviation::new(5).unwrap();
+/// assert_eq!(mad.next(2.0), 0.0);
+/// assert_eq!(mad.next(4.0), 1.0);
+/// assert_eq!(mad.next(5.0), 1.0);
+/// assert_eq!(mad.next(3.0), 1.0);
+/// assert_eq!(mad.next(100.0), 1.0);
+/// ```
+///
+/// # Links
+///
+/// * [Median absolute deviation, Wikipedia](https://en.wikipedia.org/wiki/Median_absolute_deviation)
+///
+#[doc(alias = "MedAD")]
+pub type MedianAbsoluteDeviation = MedianAbsoluteDeviationBase<Heap>;
+
+/// Median Absolute Deviation (MedAD) with the period `N` known at compile time.
+///
+/// Behaves exactly like [MedianAbsoluteDeviation], but the window is stored inline
+/// and no heap allocation is performed.
+pub type InlineMedianAbsoluteDeviation<const N: usize> = MedianAbsoluteDeviationBase<Inline<N>>;
+
+/// Median Absolute Deviation (MedAD) generic over its [Storage].
+///
+/// See [MedianAbsoluteDeviation] and [InlineMedianAbsoluteDeviation].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct MedianAbsoluteDeviationBase<S: Storage> {
+    median: MedianBase<S>,
+}
+
+impl<S: Storage> MedianAbsoluteDeviationBase<S> {
+    fn with_period(period: usize) -> Result<Self> {
+        Ok(Self {
+            median: MedianBase::with_period(period)?,
+        })
+    }
+
+    fn deviation(&self) -> f64 {
+        let sorted = self.median.sorted();
+        let median = self.median.median();
+        let len = sorted.len();
+
+        // Deviations of the values below and above the middle grow outwards,
+        // so merging both sides from the middle yields them in ascending order.
+        let mut left = len / 2;
+        let mut right = len / 2;
+        let mut lower = 0.0;
+        let mut upper = 0.0;
+        for position in 0..=len / 2 {
+            let deviation = if left > 0
+                && (right == len || median - sorted[left - 1] <= sorted[right] - median)
+            {
+                left -= 1;
+                median - sorted[left]
+            } else {
+                right += 1;
+                sorted[right - 1] - median
+            };
+
+            if position == (len - 1) / 2 {
+                lower = deviation;
+            }
+            upper = deviation;
+        }
+
+        if len % 2 == 1 {
+            upper
+        } else {
+            (lower + upper) / 2.0
+        }
+    }
+}
+
+impl MedianAbsoluteDeviation {
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_period(period)
+    }
+}
+
+impl<const N: usize> InlineMedianAbsoluteDeviation<N> {
+    pub fn new() -> Result<Self> {
+        Self::with_period(N)
+    }
+}
+
+impl<S: Storage> Period for MedianAbsoluteDeviationBase<S> {
+    fn period(&self) -> usize {
+        self.median.period()
+    }
+}
+
+impl<S: Storage> Next<f64> for MedianAbsoluteDeviationBase<S> {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.median.next(input);
+        self.deviation()
+    }
+}
+
+impl<S: Storage, T: Close> Next<&T> for MedianAbsoluteDeviationBase<S> {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl<S: Storage> Reset for MedianAbsoluteDeviationBase<S> {
+    fn reset(&mut self) {
+        self.median.reset();
+    }
+}
+
+impl Default for MedianAbsoluteDeviation {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl<const N: usize> Default for InlineMedianAbsoluteDeviation<N> {
+    fn default() -> Self {
+        Self::new().unwrap()
+    }
+}
+
+impl<S: Storage> fmt::Display for MedianAbsoluteDeviationBase<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MEDAD({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use rand::Rng;
+
+    test_indicator!(MedianAbsoluteDeviation);
+
+    mod inline {
+        use super::*;
+
+        type InlineMedAd = InlineMedianAbsoluteDeviation<9>;
+
+        test_indicator!(InlineMedAd);
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(MedianAbsoluteDeviation::new(0).is_err());
+        assert!(MedianAbsoluteDeviation::new(1).is_ok());
+        assert!(InlineMedianAbsoluteDeviation::<0>::new().is_err());
+        assert!(InlineMedianAbsoluteDeviation::<1>::new().is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        fn check<S: Storage>(mut mad: MedianAbsoluteDeviationBase<S>) {
+            assert_eq!(mad.next(1.0), 0.0);
+            assert_eq!(mad.next(2.0), 0.5);
+            assert_eq!(mad.next(3.0), 1.0);
+            assert_eq!(mad.next(4.0), 1.0);
+            assert_eq!(mad.next(10.0), 1.0);
+            assert_eq!(mad.next(3.0), 0.5);
+        }
+
+        check(MedianAbsoluteDeviation::new(4).unwrap());
+        check(InlineMedianAbsoluteDeviation::<4>::new().unwrap());
+    }
+
+    #[test]
+    fn test_next_against_sorting() {
+        fn median(values: &mut [f64]) -> f64 {
+            values.sort_by(|a, b| a.total_cmp(b));
+            let len = values.len();
+            if len % 2 == 1 {
+                values[len / 2]
+            } else {
+                (values[len / 2 - 1] + values[len / 2]) / 2.0
+            }
+        }
+
+        let mut rng = rand::thread_rng();
+        let mut mad = MedianAbsoluteDeviation::new(8).unwrap();
+        let mut inputs = Vec::new();
+
+        for _ in 0..1_000 {
+            let input = rng.gen_range(0, 20) as f64;
+            inputs.push(input);
+
+            let start = inputs.len().saturating_sub(8);
+            let mut window = inputs[start..].to_vec();
+            let center = median(&mut window);
+            let mut deviations: Vec<f64> = window.iter().map(|x| (x - center).abs()).collect();
+
+            assert_eq!(mad.next(input), median(&mut deviations));
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut mad = MedianAbsoluteDeviation::new(3).unwrap();
+        assert_eq!(mad.next(&bar(10.0)), 0.0);
+        assert_eq!(mad.next(&bar(20.0)), 5.0);
+        assert_eq!(mad.next(&bar(40.0)), 10.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        fn check<S: Storage>(mut mad: MedianAbsoluteDeviationBase<S>) {
+            assert_eq!(mad.next(1.0), 0.0);
+            assert_eq!(mad.next(2.0), 0.5);
+
+            mad.reset();
+
+            assert_eq!(mad.next(4.0), 0.0);
+            assert_eq!(mad.next(8.0), 2.0);
+        }
+
+        check(MedianAbsoluteDeviation::new(4).unwrap());
+        check(InlineMedianAbsoluteDeviation::<4>::new().unwrap());
+    }
+
+    #[test]
+    fn test_default() {
+        MedianAbsoluteDeviation::default();
+        InlineMedianAbsoluteDeviation::<9>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = MedianAbsoluteDeviation::new(10).unwrap();
+        assert_eq!(format!("{}", indicator), "MEDAD(10)");
+
+        let indicator = InlineMedianAbsoluteDeviation::<10>::new().unwrap();
+        assert_eq!(format!("{}", indicator), "MEDAD(10)");
+    }
+}
@@ -1,12 +1,19 @@
 use std::fmt;
 
-use crate::errors::{Result, TaError};
+use crate::errors::Result;
+#[cfg(feature = "serde")]
+use crate::errors::TaError;
+use crate::storage::{Heap, Inline, Storage};
+use crate::RollingWindow;
 use crate::{Low, Next, Period, Reset};
 #[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
+use serde::{de::Error, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Returns the lowest value in a given time frame.
 ///
+/// The candidates for the lowest value are kept in a monotonic deque,
+/// so every update takes amortized constant time regardless of the period.
+///
 /// # Parameters
 ///
 /// * _period_ - size of the time frame (integer greater than 0). Default value is 14.
@@ -23,72 +30,113 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(min.next(12.0), 10.0);
 /// assert_eq!(min.next(13.0), 11.0);
 /// ```
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub type Minimum = MinimumBase<Heap>;
+
+/// Returns the lowest value in a time frame of `N` values known at compile time.
+///
+/// Behaves exactly like [Minimum], but the window is stored inline
+/// and no heap allocation is performed.
+pub type InlineMinimum<const N: usize> = MinimumBase<Inline<N>>;
+
+/// Returns the lowest value in a given time frame, generic over its [Storage].
+///
+/// See [Minimum] and [InlineMinimum].
 #[derive(Debug, Clone)]
-pub struct Minimum {
-    period: usize,
-    min_index: usize,
-    cur_index: usize,
-    deque: Box<[f64]>,
+pub struct MinimumBase<S: Storage> {
+    count: usize,
+    head: usize,
+    len: usize,
+    window: RollingWindow<S>,
+    deque: S::Buffer<usize>,
 }
 
-impl Minimum {
-    pub fn new(period: usize) -> Result<Self> {
-        match period {
-            0 => Err(TaError::InvalidParameter),
-            _ => Ok(Self {
-                period,
-                min_index: 0,
-                cur_index: 0,
-                deque: vec![f64::INFINITY; period].into_boxed_slice(),
-            }),
-        }
+impl<S: Storage> MinimumBase<S> {
+    fn with_period(period: usize) -> Result<Self> {
+        Ok(Self {
+            count: 0,
+            head: 0,
+            len: 0,
+            window: RollingWindow::new(period)?,
+            deque: S::buffer(period, 0),
+        })
     }
 
-    fn find_min_index(&self) -> usize {
-        let mut min = f64::INFINITY;
-        let mut index: usize = 0;
+    /// Returns the value at the given position, it must be in the current time frame.
+    fn value(&self, index: usize) -> f64 {
+        self.window
+            .get(index + self.window.len() - self.count)
+            .unwrap()
+    }
 
-        for (i, &val) in self.deque.iter().enumerate() {
-            if val < min {
-                min = val;
-                index = i;
-            }
+    /// Returns the number of bars since the lowest value of the current time frame.
+    ///
+    /// `0` means that the last input is the lowest value. When there are several
+    /// lowest values in the time frame, the most recent one is taken.
+    pub fn age(&self) -> usize {
+        self.count.saturating_sub(1) - self.index()
+    }
+
+    /// Returns the position of the lowest value of the current time frame,
+    /// counted from the first input after creation or reset.
+    pub fn index(&self) -> usize {
+        if self.len == 0 {
+            0
+        } else {
+            S::get(&self.deque, self.head)
         }
+    }
+}
+
+impl Minimum {
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_period(period)
+    }
+}
 
-        index
+impl<const N: usize> InlineMinimum<N> {
+    pub fn new() -> Result<Self> {
+        Self::with_period(N)
     }
 }
 
-impl Period for Minimum {
+impl<S: Storage> Period for MinimumBase<S> {
     fn period(&self) -> usize {
-        self.period
+        self.window.capacity()
     }
 }
 
-impl Next<f64> for Minimum {
+impl<S: Storage> Next<f64> for MinimumBase<S> {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        self.deque[self.cur_index] = input;
+        let period = self.period();
+        let index = self.count;
+        self.window.push(input);
+        self.count += 1;
 
-        if input < self.deque[self.min_index] {
-            self.min_index = self.cur_index;
-        } else if self.min_index == self.cur_index {
-            self.min_index = self.find_min_index();
+        // The front candidate leaves the time frame
+        if self.len > 0 && S::get(&self.deque, self.head) + period <= index {
+            self.head = (self.head + 1) % period;
+            self.len -= 1;
         }
 
-        self.cur_index = if self.cur_index + 1 < self.period {
-            self.cur_index + 1
-        } else {
-            0
-        };
+        // Candidates that are not less than the input can never be the lowest value again
+        while self.len > 0 {
+            let back = S::get(&self.deque, self.head + self.len - 1);
+            if self.value(back) < input {
+                break;
+            }
+            self.len -= 1;
+        }
+
+        S::set(&mut self.deque, self.head + self.len, index);
+        self.len += 1;
 
-        self.deque[self.min_index]
+        self.value(self.index())
     }
 }
 
-impl<T: Low> Next<&T> for Minimum {
+impl<S: Storage, T: Low> Next<&T> for MinimumBase<S> {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
@@ -96,11 +144,59 @@ impl<T: Low> Next<&T> for Minimum {
     }
 }
 
-impl Reset for Minimum {
+impl<S: Storage> Reset for MinimumBase<S> {
     fn reset(&mut self) {
-        for i in 0..self.period {
-            self.deque[i] = f64::INFINITY;
+        self.count = 0;
+        self.head = 0;
+        self.len = 0;
+        self.window.clear();
+    }
+}
+
+// Only the window is serialized, the candidates are rebuilt from it on deserialization.
+// This works with any storage, including inline arrays of arbitrary size,
+// and can not produce candidates outside of the window.
+#[cfg(feature = "serde")]
+impl<S: Storage> Serialize for MinimumBase<S> {
+    fn serialize<Ser: Serializer>(
+        &self,
+        serializer: Ser,
+    ) -> std::result::Result<Ser::Ok, Ser::Error> {
+        let mut state = serializer.serialize_struct("Minimum", 2)?;
+        state.serialize_field("count", &self.count)?;
+        state.serialize_field("window", &self.window)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(bound = "")]
+struct RawMinimum<S: Storage> {
+    count: usize,
+    window: RollingWindow<S>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: Storage> Deserialize<'de> for MinimumBase<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let raw = RawMinimum::<S>::deserialize(deserializer)?;
+        if raw.count < raw.window.len() {
+            return Err(D::Error::custom(TaError::DataItemInvalid));
         }
+
+        let mut indicator = Self::with_period(raw.window.capacity()).map_err(D::Error::custom)?;
+        for value in raw.window.iter() {
+            indicator.next(value);
+        }
+
+        // Positions are counted from the first input, not from the oldest value of the window
+        let offset = raw.count - raw.window.len();
+        for index in indicator.deque.as_mut() {
+            *index += offset;
+        }
+        indicator.count = raw.count;
+        Ok(indicator)
     }
 }
 
@@ -110,9 +206,15 @@ impl Default for Minimum {
     }
 }
 
-impl fmt::Display for Minimum {
+impl<const N: usize> Default for InlineMinimum<N> {
+    fn default() -> Self {
+        Self::new().unwrap()
+    }
+}
+
+impl<S: Storage> fmt::Display for MinimumBase<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "MIN({})", self.period)
+        write!(f, "MIN({})", self.period())
     }
 }
 
@@ -120,29 +222,43 @@ impl fmt::Display for Minimum {
 mod tests {
     use super::*;
     use crate::test_helper::*;
+    use rand::Rng;
 
     test_indicator!(Minimum);
 
+    mod inline {
+        use super::*;
+
+        type InlineMin = InlineMinimum<14>;
+
+        test_indicator!(InlineMin);
+    }
+
     #[test]
     fn test_new() {
         assert!(Minimum::new(0).is_err());
         assert!(Minimum::new(1).is_ok());
+        assert!(InlineMinimum::<0>::new().is_err());
+        assert!(InlineMinimum::<1>::new().is_ok());
     }
 
     #[test]
     fn test_next() {
-        let mut min = Minimum::new(3).unwrap();
+        fn check<S: Storage>(mut min: MinimumBase<S>) {
+            assert_eq!(min.next(4.0), 4.0);
+            assert_eq!(min.next(1.2), 1.2);
+            assert_eq!(min.next(5.0), 1.2);
+            assert_eq!(min.next(3.0), 1.2);
+            assert_eq!(min.next(4.0), 3.0);
+            assert_eq!(min.next(6.0), 3.0);
+            assert_eq!(min.next(7.0), 4.0);
+            assert_eq!(min.next(8.0), 6.0);
+            assert_eq!(min.next(-9.0), -9.0);
+            assert_eq!(min.next(0.0), -9.0);
+        }
 
-        assert_eq!(min.next(4.0), 4.0);
-        assert_eq!(min.next(1.2), 1.2);
-        assert_eq!(min.next(5.0), 1.2);
-        assert_eq!(min.next(3.0), 1.2);
-        assert_eq!(min.next(4.0), 3.0);
-        assert_eq!(min.next(6.0), 3.0);
-        assert_eq!(min.next(7.0), 4.0);
-        assert_eq!(min.next(8.0), 6.0);
-        assert_eq!(min.next(-9.0), -9.0);
-        assert_eq!(min.next(0.0), -9.0);
+        check(Minimum::new(3).unwrap());
+        check(InlineMinimum::<3>::new().unwrap());
     }
 
     #[test]
@@ -151,33 +267,99 @@ mod tests {
             Bar::new().low(low)
         }
 
+        fn check<S: Storage>(mut min: MinimumBase<S>) {
+            assert_eq!(min.next(&bar(4.0)), 4.0);
+            assert_eq!(min.next(&bar(4.0)), 4.0);
+            assert_eq!(min.next(&bar(1.2)), 1.2);
+            assert_eq!(min.next(&bar(5.0)), 1.2);
+        }
+
+        check(Minimum::new(3).unwrap());
+        check(InlineMinimum::<3>::new().unwrap());
+    }
+
+    #[test]
+    fn test_next_matches_naive() {
+        let mut rng = rand::thread_rng();
+        let inputs: Vec<f64> = (0..500)
+            .map(|_| rng.gen_range(0.0, 10.0_f64).round())
+            .collect();
+
+        for &period in &[1, 2, 3, 7, 50] {
+            let mut min = Minimum::new(period).unwrap();
+
+            for (i, &input) in inputs.iter().enumerate() {
+                let window = &inputs[(i + 1).saturating_sub(period)..=i];
+                let expected = window.iter().cloned().fold(f64::INFINITY, f64::min);
+                assert_eq!(min.next(input), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_ascending() {
+        let mut min = Minimum::new(252).unwrap();
+
+        for i in 0..1000 {
+            let input = i as f64;
+            let expected = if i >= 251 { input + -251.0 } else { 0.0 };
+            assert_eq!(min.next(input), expected);
+        }
+    }
+
+    #[test]
+    fn test_age_and_index() {
         let mut min = Minimum::new(3).unwrap();
+        assert_eq!((min.age(), min.index()), (0, 0));
 
-        assert_eq!(min.next(&bar(4.0)), 4.0);
-        assert_eq!(min.next(&bar(4.0)), 4.0);
-        assert_eq!(min.next(&bar(1.2)), 1.2);
-        assert_eq!(min.next(&bar(5.0)), 1.2);
+        min.next(4.0);
+        assert_eq!((min.age(), min.index()), (0, 0));
+        min.next(5.0);
+        assert_eq!((min.age(), min.index()), (1, 0));
+        min.next(1.2);
+        assert_eq!((min.age(), min.index()), (0, 2));
+        min.next(3.0);
+        assert_eq!((min.age(), min.index()), (1, 2));
+        min.next(1.2);
+        assert_eq!((min.age(), min.index()), (0, 4));
+        min.next(6.0);
+        assert_eq!((min.age(), min.index()), (1, 4));
+        min.next(7.0);
+        assert_eq!((min.age(), min.index()), (2, 4));
+        min.next(8.0);
+        assert_eq!((min.age(), min.index()), (2, 5));
+
+        min.reset();
+        min.next(1.0);
+        assert_eq!((min.age(), min.index()), (0, 0));
     }
 
     #[test]
     fn test_reset() {
-        let mut min = Minimum::new(10).unwrap();
+        fn check<S: Storage>(mut min: MinimumBase<S>) {
+            assert_eq!(min.next(5.0), 5.0);
+            assert_eq!(min.next(7.0), 5.0);
 
-        assert_eq!(min.next(5.0), 5.0);
-        assert_eq!(min.next(7.0), 5.0);
+            min.reset();
+            assert_eq!(min.next(8.0), 8.0);
+        }
 
-        min.reset();
-        assert_eq!(min.next(8.0), 8.0);
+        check(Minimum::new(10).unwrap());
+        check(InlineMinimum::<10>::new().unwrap());
     }
 
     #[test]
     fn test_default() {
         Minimum::default();
+        InlineMinimum::<14>::default();
     }
 
     #[test]
     fn test_display() {
         let indicator = Minimum::new(10).unwrap();
         assert_eq!(format!("{}", indicator), "MIN(10)");
+
+        let indicator = InlineMinimum::<10>::new().unwrap();
+        assert_eq!(format!("{}", indicator), "MIN(10)");
     }
 }
@@ -1,39 +1,115 @@
+mod direction;
+pub use self::direction::Direction;
+
+pub(crate) mod moving_average;
+pub use self::moving_average::MovingAverageType;
+
 mod exponential_moving_average;
 pub use self::exponential_moving_average::ExponentialMovingAverage;
 
+mod double_exponential_moving_average;
+pub use self::double_exponential_moving_average::DoubleExponentialMovingAverage;
+
+mod triple_exponential_moving_average;
+pub use self::triple_exponential_moving_average::TripleExponentialMovingAverage;
+
+mod zero_lag_exponential_moving_average;
+pub use self::zero_lag_exponential_moving_average::ZeroLagExponentialMovingAverage;
+
+mod t3_moving_average;
+pub use self::t3_moving_average::T3MovingAverage;
+
+mod trix;
+pub use self::trix::{Trix, TrixOutput};
+
 mod weighted_moving_average;
-pub use self::weighted_moving_average::WeightedMovingAverage;
+pub use self::weighted_moving_average::{
+    InlineWeightedMovingAverage, WeightedMovingAverage, WeightedMovingAverageBase,
+};
+
+mod smoothed_moving_average;
+pub use self::smoothed_moving_average::SmoothedMovingAverage;
+
+mod volume_weighted_moving_average;
+pub use self::volume_weighted_moving_average::VolumeWeightedMovingAverage;
+
+mod hull_moving_average;
+pub use self::hull_moving_average::HullMovingAverage;
+
+mod arnaud_legoux_moving_average;
+pub use self::arnaud_legoux_moving_average::ArnaudLegouxMovingAverage;
+
+mod mcginley_dynamic;
+pub use self::mcginley_dynamic::McGinleyDynamic;
+
+mod variable_index_dynamic_average;
+pub use self::variable_index_dynamic_average::VariableIndexDynamicAverage;
+
+mod fractal_adaptive_moving_average;
+pub use self::fractal_adaptive_moving_average::FractalAdaptiveMovingAverage;
 
 mod simple_moving_average;
-pub use self::simple_moving_average::SimpleMovingAverage;
+pub use self::simple_moving_average::{
+    InlineSimpleMovingAverage, SimpleMovingAverage, SimpleMovingAverageBase,
+};
+
+mod variance;
+pub use self::variance::{InlineVariance, Variance, VarianceBase, VarianceEstimator};
 
 mod standard_deviation;
-pub use self::standard_deviation::StandardDeviation;
+pub use self::standard_deviation::{
+    InlineStandardDeviation, StandardDeviation, StandardDeviationBase,
+};
 
 mod mean_absolute_deviation;
-pub use self::mean_absolute_deviation::MeanAbsoluteDeviation;
+pub use self::mean_absolute_deviation::{
+    InlineMeanAbsoluteDeviation, MeanAbsoluteDeviation, MeanAbsoluteDeviationBase,
+};
+
+mod median;
+pub use self::median::{InlineMedian, Median, MedianBase};
+
+mod median_absolute_deviation;
+pub use self::median_absolute_deviation::{
+    InlineMedianAbsoluteDeviation, MedianAbsoluteDeviation, MedianAbsoluteDeviationBase,
+};
 
 mod relative_strength_index;
 pub use self::relative_strength_index::RelativeStrengthIndex;
 
 mod minimum;
-pub use self::minimum::Minimum;
+pub use self::minimum::{InlineMinimum, Minimum, MinimumBase};
 
 mod maximum;
-pub use self::maximum::Maximum;
+pub use self::maximum::{InlineMaximum, Maximum, MaximumBase};
 
 mod fast_stochastic;
 pub use self::fast_stochastic::FastStochastic;
 
+mod full_stochastic;
+pub use self::full_stochastic::{FullStochastic, FullStochasticOutput};
+
 mod slow_stochastic;
 pub use self::slow_stochastic::SlowStochastic;
 
+mod stochastic_rsi;
+pub use self::stochastic_rsi::{StochasticRsi, StochasticRsiOutput};
+
+mod williams_r;
+pub use self::williams_r::WilliamsR;
+
 mod true_range;
 pub use self::true_range::TrueRange;
 
 mod average_true_range;
 pub use self::average_true_range::AverageTrueRange;
 
+mod normalized_average_true_range;
+pub use self::normalized_average_true_range::NormalizedAverageTrueRange;
+
+mod average_true_range_percent_rank;
+pub use self::average_true_range_percent_rank::AverageTrueRangePercentRank;
+
 mod moving_average_convergence_divergence;
 pub use self::moving_average_convergence_divergence::{
     MovingAverageConvergenceDivergence, MovingAverageConvergenceDivergenceOutput,
@@ -53,17 +129,52 @@ pub use self::efficiency_ratio::EfficiencyRatio;
 mod bollinger_bands;
 pub use self::bollinger_bands::{BollingerBands, BollingerBandsOutput};
 
+mod squeeze;
+pub use self::squeeze::{Squeeze, SqueezeOutput};
+
 mod chandelier_exit;
-pub use self::chandelier_exit::{ChandelierExit, ChandelierExitOutput};
+pub use self::chandelier_exit::{ChandelierExit, ChandelierExitExtremes, ChandelierExitOutput};
+
+mod supertrend;
+pub use self::supertrend::{Supertrend, SupertrendOutput};
+
+mod donchian_channel;
+pub use self::donchian_channel::{DonchianChannel, DonchianChannelOutput};
 
 mod keltner_channel;
-pub use self::keltner_channel::{KeltnerChannel, KeltnerChannelOutput};
+pub use self::keltner_channel::{KeltnerChannel, KeltnerChannelMethod, KeltnerChannelOutput};
+
+mod lag;
 
 mod rate_of_change;
 pub use self::rate_of_change::RateOfChange;
 
+mod rate_of_change_percentage;
+pub use self::rate_of_change_percentage::RateOfChangePercentage;
+
+mod rate_of_change_ratio;
+pub use self::rate_of_change_ratio::RateOfChangeRatio;
+
+mod rate_of_change_ratio_100;
+pub use self::rate_of_change_ratio_100::RateOfChangeRatio100;
+
+mod momentum;
+pub use self::momentum::Momentum;
+
+mod log_return;
+pub use self::log_return::LogReturn;
+
 mod money_flow_index;
 pub use self::money_flow_index::MoneyFlowIndex;
 
 mod on_balance_volume;
 pub use self::on_balance_volume::OnBalanceVolume;
+
+mod arg_maximum;
+pub use self::arg_maximum::{ArgMaximum, ArgMaximumOutput};
+
+mod arg_minimum;
+pub use self::arg_minimum::{ArgMinimum, ArgMinimumOutput};
+
+mod aroon;
+pub use self::aroon::{Aroon, AroonOutput};
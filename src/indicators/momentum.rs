@@ -0,0 +1,72 @@
+use crate::indicators::lag::lag_indicator;
+
+lag_indicator! {
+    /// Momentum (MOM)
+    ///
+    /// Absolute change of the price over the last _n_ periods.
+    ///
+    /// # Formula
+    ///
+    /// MOM = P<sub>t</sub> - P<sub>t-n</sub>
+    ///
+    /// Where:
+    ///
+    /// * P<sub>t</sub> - price at the moment
+    /// * P<sub>t-n</sub> - price _n_ periods ago
+    ///
+    /// # Parameters
+    ///
+    /// * _period_ - number of periods integer greater than 0
+    ///
+    /// Until there is a price _n_ periods ago, the first price is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ta::indicators::Momentum;
+    /// use ta::Next;
+    ///
+    /// let mut mom = Momentum::new(2).unwrap();
+    /// assert_eq!(mom.next(10.0), 0.0);
+    /// assert_eq!(mom.next(9.5), -0.5);
+    /// assert_eq!(mom.next(12.0), 2.0);
+    /// assert_eq!(mom.next(13.0), 3.5);
+    /// ```
+    ///
+    /// # Links
+    ///
+    /// * [Momentum, Wikipedia](https://en.wikipedia.org/wiki/Momentum_(technical_analysis))
+    ///
+    #[doc(alias = "MOM")]
+    pub struct Momentum;
+    display = "MOM",
+    default_period = 10,
+    formula = |input, previous| input - previous,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::traits::Next;
+
+    #[test]
+    fn test_next_f64() {
+        let mut mom = Momentum::new(3).unwrap();
+
+        assert_eq!(round(mom.next(10.0)), 0.0);
+        assert_eq!(round(mom.next(10.4)), 0.4);
+        assert_eq!(round(mom.next(10.57)), 0.57);
+        assert_eq!(round(mom.next(10.8)), 0.8);
+        assert_eq!(round(mom.next(10.9)), 0.5);
+        assert_eq!(round(mom.next(10.0)), -0.57);
+    }
+
+    #[test]
+    fn test_next_zero() {
+        let mut mom = Momentum::new(1).unwrap();
+
+        assert_eq!(mom.next(0.0), 0.0);
+        assert_eq!(mom.next(5.0), 5.0);
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt;
 
-use crate::errors::{Result, TaError};
-use crate::{Close, High, Low, Next, Period, Reset, Volume};
+use crate::errors::Result;
+use crate::{Close, High, Low, Next, Period, Reset, RollingWindow, Volume};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -52,40 +52,32 @@ use serde::{Deserialize, Serialize};
 /// # Links
 /// * [Money Flow Index, Wikipedia](https://en.wikipedia.org/wiki/Money_flow_index)
 /// * [Money Flow Index, stockcharts](https://stockcharts.com/school/doku.php?id=chart_school:technical_indicators:money_flow_index_mfi)
-
 #[doc(alias = "MFI")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct MoneyFlowIndex {
-    period: usize,
-    index: usize,
-    count: usize,
     previous_typical_price: f64,
     total_positive_money_flow: f64,
     total_negative_money_flow: f64,
-    deque: Box<[f64]>,
+    is_new: bool,
+    window: RollingWindow,
 }
 
 impl MoneyFlowIndex {
     pub fn new(period: usize) -> Result<Self> {
-        match period {
-            0 => Err(TaError::InvalidParameter),
-            _ => Ok(Self {
-                period,
-                index: 0,
-                count: 0,
-                previous_typical_price: 0.0,
-                total_positive_money_flow: 0.0,
-                total_negative_money_flow: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
-            }),
-        }
+        Ok(Self {
+            previous_typical_price: 0.0,
+            total_positive_money_flow: 0.0,
+            total_negative_money_flow: 0.0,
+            is_new: true,
+            window: RollingWindow::new(period)?,
+        })
     }
 }
 
 impl Period for MoneyFlowIndex {
     fn period(&self) -> usize {
-        self.period
+        self.window.capacity()
     }
 }
 
@@ -95,40 +87,33 @@ impl<T: High + Low + Close + Volume> Next<&T> for MoneyFlowIndex {
     fn next(&mut self, input: &T) -> f64 {
         let tp = (input.close() + input.high() + input.low()) / 3.0;
 
-        self.index = if self.index + 1 < self.period {
-            self.index + 1
-        } else {
-            0
-        };
-
-        if self.count < self.period {
-            self.count = self.count + 1;
-            if self.count == 1 {
-                self.previous_typical_price = tp;
-                return 50.0;
-            }
-        } else {
-            let popped = self.deque[self.index];
-            if popped.is_sign_positive() {
-                self.total_positive_money_flow -= popped;
-            } else {
-                self.total_negative_money_flow += popped;
-            }
+        if self.is_new {
+            self.is_new = false;
+            self.previous_typical_price = tp;
+            return 50.0;
         }
 
-        if tp > self.previous_typical_price {
+        let money_flow = if tp > self.previous_typical_price {
             let raw_money_flow = tp * input.volume();
             self.total_positive_money_flow += raw_money_flow;
-            self.deque[self.index] = raw_money_flow;
+            raw_money_flow
         } else if tp < self.previous_typical_price {
             let raw_money_flow = tp * input.volume();
             self.total_negative_money_flow += raw_money_flow;
-            self.deque[self.index] = -raw_money_flow;
+            -raw_money_flow
         } else {
-            self.deque[self.index] = 0.0;
-        }
+            0.0
+        };
         self.previous_typical_price = tp;
 
+        if let Some(popped) = self.window.push(money_flow) {
+            if popped.is_sign_positive() {
+                self.total_positive_money_flow -= popped;
+            } else {
+                self.total_negative_money_flow += popped;
+            }
+        }
+
         self.total_positive_money_flow
             / (self.total_positive_money_flow + self.total_negative_money_flow)
             * 100.0
@@ -143,20 +128,17 @@ impl Default for MoneyFlowIndex {
 
 impl fmt::Display for MoneyFlowIndex {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "MFI({})", self.period)
+        write!(f, "MFI({})", self.period())
     }
 }
 
 impl Reset for MoneyFlowIndex {
     fn reset(&mut self) {
-        self.index = 0;
-        self.count = 0;
         self.previous_typical_price = 0.0;
         self.total_positive_money_flow = 0.0;
         self.total_negative_money_flow = 0.0;
-        for i in 0..self.period {
-            self.deque[i] = 0.0;
-        }
+        self.is_new = true;
+        self.window.clear();
     }
 }
 
@@ -0,0 +1,111 @@
+use crate::errors::Result;
+use crate::indicators::{ExponentialMovingAverage, SimpleMovingAverage, WeightedMovingAverage};
+use crate::{Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kind of moving average used by indicators with a selectable smoothing.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovingAverageType {
+    /// [Simple Moving Average (SMA)](crate::indicators::SimpleMovingAverage)
+    Simple,
+    /// [Exponential Moving Average (EMA)](crate::indicators::ExponentialMovingAverage)
+    Exponential,
+    /// [Weighted Moving Average (WMA)](crate::indicators::WeightedMovingAverage)
+    Weighted,
+}
+
+/// Moving average of the given [MovingAverageType].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub(crate) enum MovingAverage {
+    Simple(SimpleMovingAverage),
+    Exponential(ExponentialMovingAverage),
+    Weighted(WeightedMovingAverage),
+}
+
+impl MovingAverage {
+    pub fn new(kind: MovingAverageType, period: usize) -> Result<Self> {
+        Ok(match kind {
+            MovingAverageType::Simple => Self::Simple(SimpleMovingAverage::new(period)?),
+            MovingAverageType::Exponential => {
+                Self::Exponential(ExponentialMovingAverage::new(period)?)
+            }
+            MovingAverageType::Weighted => Self::Weighted(WeightedMovingAverage::new(period)?),
+        })
+    }
+
+    pub fn kind(&self) -> MovingAverageType {
+        match self {
+            Self::Simple(_) => MovingAverageType::Simple,
+            Self::Exponential(_) => MovingAverageType::Exponential,
+            Self::Weighted(_) => MovingAverageType::Weighted,
+        }
+    }
+}
+
+impl Period for MovingAverage {
+    fn period(&self) -> usize {
+        match self {
+            Self::Simple(ma) => ma.period(),
+            Self::Exponential(ma) => ma.period(),
+            Self::Weighted(ma) => ma.period(),
+        }
+    }
+}
+
+impl Next<f64> for MovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        match self {
+            Self::Simple(ma) => ma.next(input),
+            Self::Exponential(ma) => ma.next(input),
+            Self::Weighted(ma) => ma.next(input),
+        }
+    }
+}
+
+impl Reset for MovingAverage {
+    fn reset(&mut self) {
+        match self {
+            Self::Simple(ma) => ma.reset(),
+            Self::Exponential(ma) => ma.reset(),
+            Self::Weighted(ma) => ma.reset(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(MovingAverage::new(MovingAverageType::Simple, 0).is_err());
+        assert!(MovingAverage::new(MovingAverageType::Exponential, 0).is_err());
+        assert!(MovingAverage::new(MovingAverageType::Weighted, 0).is_err());
+
+        let ma = MovingAverage::new(MovingAverageType::Weighted, 3).unwrap();
+        assert_eq!(ma.kind(), MovingAverageType::Weighted);
+        assert_eq!(ma.period(), 3);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut sma = MovingAverage::new(MovingAverageType::Simple, 2).unwrap();
+        let mut ema = MovingAverage::new(MovingAverageType::Exponential, 2).unwrap();
+        let mut wma = MovingAverage::new(MovingAverageType::Weighted, 2).unwrap();
+
+        for ma in [&mut sma, &mut ema, &mut wma] {
+            assert_eq!(ma.next(3.0), 3.0);
+        }
+        assert_eq!(sma.next(6.0), 4.5);
+        assert_eq!(ema.next(6.0), 5.0);
+        assert_eq!(wma.next(6.0), 5.0);
+
+        sma.reset();
+        assert_eq!(sma.next(1.0), 1.0);
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::AverageTrueRange;
+use crate::{Close, High, Low, Next, Period, Reset};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Normalized average true range (NATR).
+///
+/// The [average true range](struct.AverageTrueRange.html) expressed as a percentage
+/// of the close price. Unlike ATR, it can be used to compare the volatility of
+/// instruments traded at very different price levels.
+///
+/// # Formula
+///
+/// NATR(period)<sub>t</sub> = ATR(period)<sub>t</sub> / close<sub>t</sub> * 100
+///
+/// If the close price is 0, NATR is 0.
+///
+/// # Parameters
+///
+/// * _period_ - smoothing period of ATR (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::NormalizedAverageTrueRange;
+/// use ta::{DataItem, Next};
+///
+/// let mut natr = NormalizedAverageTrueRange::new(3).unwrap();
+///
+/// let di = DataItem::builder()
+///     .open(9.5)
+///     .high(11.0)
+///     .low(9.0)
+///     .close(10.0)
+///     .volume(1000.0)
+///     .build()
+///     .unwrap();
+///
+/// // ATR = 2.0, close = 10.0
+/// assert_eq!(natr.next(&di), 20.0);
+/// ```
+///
+/// # Links
+///
+/// * [Normalized Average True Range, TA-Lib](https://ta-lib.org/function.html)
+///
+#[doc(alias = "NATR")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct NormalizedAverageTrueRange {
+    atr: AverageTrueRange,
+}
+
+impl NormalizedAverageTrueRange {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            atr: AverageTrueRange::new(period)?,
+        })
+    }
+}
+
+fn normalize(atr: f64, close: f64) -> f64 {
+    if close == 0.0 {
+        0.0
+    } else {
+        atr / close * 100.0
+    }
+}
+
+impl Period for NormalizedAverageTrueRange {
+    fn period(&self) -> usize {
+        self.atr.period()
+    }
+}
+
+impl Next<f64> for NormalizedAverageTrueRange {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        normalize(self.atr.next(input), input)
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for NormalizedAverageTrueRange {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        normalize(self.atr.next(input), input.close())
+    }
+}
+
+impl Reset for NormalizedAverageTrueRange {
+    fn reset(&mut self) {
+        self.atr.reset();
+    }
+}
+
+impl Default for NormalizedAverageTrueRange {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for NormalizedAverageTrueRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NATR({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(NormalizedAverageTrueRange);
+
+    #[test]
+    fn test_new() {
+        assert!(NormalizedAverageTrueRange::new(0).is_err());
+        assert!(NormalizedAverageTrueRange::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut natr = NormalizedAverageTrueRange::new(3).unwrap();
+
+        let bar1 = Bar::new().high(10).low(7.5).close(9);
+        let bar2 = Bar::new().high(11).low(9).close(9.5);
+        let bar3 = Bar::new().high(9).low(5).close(8);
+
+        // ATR: 2.5, 2.25, 3.375
+        assert_eq!(round(natr.next(&bar1)), 27.778);
+        assert_eq!(round(natr.next(&bar2)), 23.684);
+        assert_eq!(round(natr.next(&bar3)), 42.188);
+    }
+
+    #[test]
+    fn test_next_with_f64() {
+        let mut natr = NormalizedAverageTrueRange::new(3).unwrap();
+
+        // ATR: 0.0, 2.5, 4.25
+        assert_eq!(natr.next(10.0), 0.0);
+        assert_eq!(round(natr.next(15.0)), 16.667);
+        assert_eq!(round(natr.next(21.0)), 20.238);
+    }
+
+    #[test]
+    fn test_next_with_zero_close() {
+        let mut natr = NormalizedAverageTrueRange::new(3).unwrap();
+
+        assert_eq!(natr.next(&Bar::new().high(1).low(0).close(0)), 0.0);
+        assert_eq!(natr.next(&Bar::new().high(2).low(1).close(2)), 75.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut natr = NormalizedAverageTrueRange::new(9).unwrap();
+
+        natr.next(&Bar::new().high(10).low(7.5).close(9));
+        natr.next(&Bar::new().high(11).low(9).close(9.5));
+
+        natr.reset();
+        assert_eq!(natr.next(&Bar::new().high(60).low(15).close(50)), 90.0);
+    }
+
+    #[test]
+    fn test_default() {
+        NormalizedAverageTrueRange::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = NormalizedAverageTrueRange::new(8).unwrap();
+        assert_eq!(format!("{}", indicator), "NATR(8)");
+    }
+}
@@ -0,0 +1,77 @@
+use crate::indicators::lag::lag_indicator;
+
+lag_indicator! {
+    /// Rate of Change Percentage (ROCP)
+    ///
+    /// Change of the price over the last _n_ periods as a fraction of the price _n_ periods ago.
+    /// It is the [Rate of Change](crate::indicators::RateOfChange) without the factor 100.
+    ///
+    /// # Formula
+    ///
+    /// ROCP = (P<sub>t</sub> - P<sub>t-n</sub>) / P<sub>t-n</sub>
+    ///
+    /// Where:
+    ///
+    /// * P<sub>t</sub> - price at the moment
+    /// * P<sub>t-n</sub> - price _n_ periods ago
+    ///
+    /// # Parameters
+    ///
+    /// * _period_ - number of periods integer greater than 0
+    ///
+    /// When the price _n_ periods ago is 0, the change is undefined and 0 is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ta::indicators::RateOfChangePercentage;
+    /// use ta::Next;
+    ///
+    /// let mut rocp = RateOfChangePercentage::new(2).unwrap();
+    /// assert_eq!(rocp.next(10.0), 0.0);
+    /// assert_eq!(rocp.next(9.5), -0.05);
+    /// assert_eq!(rocp.next(12.0), 0.2);
+    /// ```
+    ///
+    /// # Links
+    ///
+    /// * [Rate of Change, Wikipedia](https://en.wikipedia.org/wiki/Momentum_(technical_analysis))
+    ///
+    #[doc(alias = "ROCP")]
+    pub struct RateOfChangePercentage;
+    display = "ROCP",
+    default_period = 9,
+    formula = |input, previous| if previous == 0.0 {
+        0.0
+    } else {
+        (input - previous) / previous
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::traits::Next;
+
+    #[test]
+    fn test_next_f64() {
+        let mut rocp = RateOfChangePercentage::new(3).unwrap();
+
+        assert_eq!(round(rocp.next(10.0)), 0.0);
+        assert_eq!(round(rocp.next(10.4)), 0.04);
+        assert_eq!(round(rocp.next(10.57)), 0.057);
+        assert_eq!(round(rocp.next(10.8)), 0.08);
+        assert_eq!(round(rocp.next(10.9)), 0.048);
+        assert_eq!(round(rocp.next(10.0)), -0.054);
+    }
+
+    #[test]
+    fn test_next_zero() {
+        let mut rocp = RateOfChangePercentage::new(1).unwrap();
+
+        assert_eq!(rocp.next(0.0), 0.0);
+        assert_eq!(rocp.next(5.0), 0.0);
+        assert_eq!(rocp.next(10.0), 1.0);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::indicators::lag::lag_indicator;
+
+lag_indicator! {
+    /// Rate of Change Ratio (ROCR)
+    ///
+    /// Ratio of the price to the price _n_ periods ago.
+    ///
+    /// # Formula
+    ///
+    /// ROCR = P<sub>t</sub> / P<sub>t-n</sub>
+    ///
+    /// Where:
+    ///
+    /// * P<sub>t</sub> - price at the moment
+    /// * P<sub>t-n</sub> - price _n_ periods ago
+    ///
+    /// # Parameters
+    ///
+    /// * _period_ - number of periods integer greater than 0
+    ///
+    /// When the price _n_ periods ago is 0, the ratio is undefined and 1 is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ta::indicators::RateOfChangeRatio;
+    /// use ta::Next;
+    ///
+    /// let mut rocr = RateOfChangeRatio::new(2).unwrap();
+    /// assert_eq!(rocr.next(10.0), 1.0);
+    /// assert_eq!(rocr.next(9.5), 0.95);
+    /// assert_eq!(rocr.next(12.0), 1.2);
+    /// ```
+    ///
+    /// # Links
+    ///
+    /// * [Rate of Change, Wikipedia](https://en.wikipedia.org/wiki/Momentum_(technical_analysis))
+    ///
+    #[doc(alias = "ROCR")]
+    pub struct RateOfChangeRatio;
+    display = "ROCR",
+    default_period = 9,
+    formula = |input, previous| if previous == 0.0 {
+        1.0
+    } else {
+        input / previous
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::traits::Next;
+
+    #[test]
+    fn test_next_f64() {
+        let mut rocr = RateOfChangeRatio::new(3).unwrap();
+
+        assert_eq!(round(rocr.next(10.0)), 1.0);
+        assert_eq!(round(rocr.next(10.4)), 1.04);
+        assert_eq!(round(rocr.next(10.57)), 1.057);
+        assert_eq!(round(rocr.next(10.8)), 1.08);
+        assert_eq!(round(rocr.next(10.9)), 1.048);
+        assert_eq!(round(rocr.next(10.0)), 0.946);
+    }
+
+    #[test]
+    fn test_next_zero() {
+        let mut rocr = RateOfChangeRatio::new(1).unwrap();
+
+        assert_eq!(rocr.next(0.0), 1.0);
+        assert_eq!(rocr.next(5.0), 1.0);
+        assert_eq!(rocr.next(10.0), 2.0);
+    }
+}
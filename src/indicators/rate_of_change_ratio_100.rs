@@ -0,0 +1,76 @@
+use crate::indicators::lag::lag_indicator;
+
+lag_indicator! {
+    /// Rate of Change Ratio 100 scale (ROCR100)
+    ///
+    /// Ratio of the price to the price _n_ periods ago, multiplied by 100.
+    ///
+    /// # Formula
+    ///
+    /// ROCR100 = P<sub>t</sub> / P<sub>t-n</sub> * 100
+    ///
+    /// Where:
+    ///
+    /// * P<sub>t</sub> - price at the moment
+    /// * P<sub>t-n</sub> - price _n_ periods ago
+    ///
+    /// # Parameters
+    ///
+    /// * _period_ - number of periods integer greater than 0
+    ///
+    /// When the price _n_ periods ago is 0, the ratio is undefined and 100 is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ta::indicators::RateOfChangeRatio100;
+    /// use ta::Next;
+    ///
+    /// let mut rocr = RateOfChangeRatio100::new(2).unwrap();
+    /// assert_eq!(rocr.next(10.0), 100.0);
+    /// assert_eq!(rocr.next(9.5), 95.0);
+    /// assert_eq!(rocr.next(12.0), 120.0);
+    /// ```
+    ///
+    /// # Links
+    ///
+    /// * [Rate of Change, Wikipedia](https://en.wikipedia.org/wiki/Momentum_(technical_analysis))
+    ///
+    #[doc(alias = "ROCR100")]
+    pub struct RateOfChangeRatio100;
+    display = "ROCR100",
+    default_period = 9,
+    formula = |input, previous| if previous == 0.0 {
+        100.0
+    } else {
+        input / previous * 100.0
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use crate::traits::Next;
+
+    #[test]
+    fn test_next_f64() {
+        let mut rocr = RateOfChangeRatio100::new(3).unwrap();
+
+        assert_eq!(round(rocr.next(10.0)), 100.0);
+        assert_eq!(round(rocr.next(10.4)), 104.0);
+        assert_eq!(round(rocr.next(10.57)), 105.7);
+        assert_eq!(round(rocr.next(10.8)), 108.0);
+        assert_eq!(round(rocr.next(10.9)), 104.808);
+        assert_eq!(round(rocr.next(10.0)), 94.607);
+    }
+
+    #[test]
+    fn test_next_zero() {
+        let mut rocr = RateOfChangeRatio100::new(1).unwrap();
+
+        assert_eq!(rocr.next(0.0), 100.0);
+        assert_eq!(rocr.next(5.0), 100.0);
+        assert_eq!(rocr.next(10.0), 200.0);
+    }
+}
@@ -1,7 +1,9 @@
 use std::fmt;
 
-use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::errors::Result;
+use crate::helpers::CompensatedSum;
+use crate::storage::{Heap, Inline, Storage};
+use crate::{Close, Next, Period, Reset, RollingWindow};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +23,11 @@ use serde::{Deserialize, Serialize};
 ///
 /// * _period_ - number of periods (integer greater than 0)
 ///
+/// The running sum is compensated (Neumaier summation), so the rounding errors of large
+/// values leaving the window do not distort the average of the small values that follow.
+/// It is also recomputed from the window once every _period_ updates, so the compensation
+/// does not accumulate over long-running streams.
+///
 /// # Example
 ///
 /// ```
@@ -39,60 +46,89 @@ use serde::{Deserialize, Serialize};
 /// * [Simple Moving Average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Simple_moving_average)
 ///
 #[doc(alias = "SMA")]
+pub type SimpleMovingAverage = SimpleMovingAverageBase<Heap>;
+
+/// Simple moving average (SMA) with the period `N` known at compile time.
+///
+/// Behaves exactly like [SimpleMovingAverage], but the window is stored inline
+/// and no heap allocation is performed.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::InlineSimpleMovingAverage;
+/// use ta::Next;
+///
+/// let mut sma = InlineSimpleMovingAverage::<3>::new().unwrap();
+/// assert_eq!(sma.next(10.0), 10.0);
+/// assert_eq!(sma.next(11.0), 10.5);
+/// assert_eq!(sma.next(12.0), 11.0);
+/// assert_eq!(sma.next(13.0), 12.0);
+/// ```
+pub type InlineSimpleMovingAverage<const N: usize> = SimpleMovingAverageBase<Inline<N>>;
+
+/// Simple moving average (SMA) generic over its [Storage].
+///
+/// See [SimpleMovingAverage] and [InlineSimpleMovingAverage].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct SimpleMovingAverage {
-    period: usize,
-    index: usize,
-    count: usize,
-    sum: f64,
-    deque: Box<[f64]>,
+pub struct SimpleMovingAverageBase<S: Storage> {
+    sum: CompensatedSum,
+    updates: usize,
+    window: RollingWindow<S>,
+}
+
+impl<S: Storage> SimpleMovingAverageBase<S> {
+    fn with_period(period: usize) -> Result<Self> {
+        Ok(Self {
+            sum: CompensatedSum::default(),
+            updates: 0,
+            window: RollingWindow::new(period)?,
+        })
+    }
 }
 
 impl SimpleMovingAverage {
     pub fn new(period: usize) -> Result<Self> {
-        match period {
-            0 => Err(TaError::InvalidParameter),
-            _ => Ok(Self {
-                period,
-                index: 0,
-                count: 0,
-                sum: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
-            }),
-        }
+        Self::with_period(period)
+    }
+}
+
+impl<const N: usize> InlineSimpleMovingAverage<N> {
+    pub fn new() -> Result<Self> {
+        Self::with_period(N)
     }
 }
 
-impl Period for SimpleMovingAverage {
+impl<S: Storage> Period for SimpleMovingAverageBase<S> {
     fn period(&self) -> usize {
-        self.period
+        self.window.capacity()
     }
 }
 
-impl Next<f64> for SimpleMovingAverage {
+impl<S: Storage> Next<f64> for SimpleMovingAverageBase<S> {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        let old_val = self.deque[self.index];
-        self.deque[self.index] = input;
-
-        self.index = if self.index + 1 < self.period {
-            self.index + 1
-        } else {
-            0
-        };
-
-        if self.count < self.period {
-            self.count += 1;
+        match self.window.push(input) {
+            None => self.sum.add(input),
+            Some(old_val) => {
+                self.updates += 1;
+                if self.updates == self.window.capacity() {
+                    self.updates = 0;
+                    self.sum = self.window.iter().collect();
+                } else {
+                    self.sum.add(-old_val);
+                    self.sum.add(input);
+                }
+            }
         }
 
-        self.sum = self.sum - old_val + input;
-        self.sum / (self.count as f64)
+        self.sum.value() / (self.window.len() as f64)
     }
 }
 
-impl<T: Close> Next<&T> for SimpleMovingAverage {
+impl<S: Storage, T: Close> Next<&T> for SimpleMovingAverageBase<S> {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
@@ -100,14 +136,11 @@ impl<T: Close> Next<&T> for SimpleMovingAverage {
     }
 }
 
-impl Reset for SimpleMovingAverage {
+impl<S: Storage> Reset for SimpleMovingAverageBase<S> {
     fn reset(&mut self) {
-        self.index = 0;
-        self.count = 0;
-        self.sum = 0.0;
-        for i in 0..self.period {
-            self.deque[i] = 0.0;
-        }
+        self.sum.clear();
+        self.updates = 0;
+        self.window.clear();
     }
 }
 
@@ -117,9 +150,15 @@ impl Default for SimpleMovingAverage {
     }
 }
 
-impl fmt::Display for SimpleMovingAverage {
+impl<const N: usize> Default for InlineSimpleMovingAverage<N> {
+    fn default() -> Self {
+        Self::new().unwrap()
+    }
+}
+
+impl<S: Storage> fmt::Display for SimpleMovingAverageBase<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "SMA({})", self.period)
+        write!(f, "SMA({})", self.period())
     }
 }
 
@@ -127,25 +166,40 @@ impl fmt::Display for SimpleMovingAverage {
 mod tests {
     use super::*;
     use crate::test_helper::*;
+    use rand::Rng;
 
     test_indicator!(SimpleMovingAverage);
 
+    mod inline {
+        use super::*;
+
+        type InlineSma = InlineSimpleMovingAverage<9>;
+
+        test_indicator!(InlineSma);
+    }
+
     #[test]
     fn test_new() {
         assert!(SimpleMovingAverage::new(0).is_err());
         assert!(SimpleMovingAverage::new(1).is_ok());
+        assert!(InlineSimpleMovingAverage::<0>::new().is_err());
+        assert!(InlineSimpleMovingAverage::<1>::new().is_ok());
     }
 
     #[test]
     fn test_next() {
-        let mut sma = SimpleMovingAverage::new(4).unwrap();
-        assert_eq!(sma.next(4.0), 4.0);
-        assert_eq!(sma.next(5.0), 4.5);
-        assert_eq!(sma.next(6.0), 5.0);
-        assert_eq!(sma.next(6.0), 5.25);
-        assert_eq!(sma.next(6.0), 5.75);
-        assert_eq!(sma.next(6.0), 6.0);
-        assert_eq!(sma.next(2.0), 5.0);
+        fn check<S: Storage>(mut sma: SimpleMovingAverageBase<S>) {
+            assert_eq!(sma.next(4.0), 4.0);
+            assert_eq!(sma.next(5.0), 4.5);
+            assert_eq!(sma.next(6.0), 5.0);
+            assert_eq!(sma.next(6.0), 5.25);
+            assert_eq!(sma.next(6.0), 5.75);
+            assert_eq!(sma.next(6.0), 6.0);
+            assert_eq!(sma.next(2.0), 5.0);
+        }
+
+        check(SimpleMovingAverage::new(4).unwrap());
+        check(InlineSimpleMovingAverage::<4>::new().unwrap());
     }
 
     #[test]
@@ -154,32 +208,64 @@ mod tests {
             Bar::new().close(close)
         }
 
-        let mut sma = SimpleMovingAverage::new(3).unwrap();
-        assert_eq!(sma.next(&bar(4.0)), 4.0);
-        assert_eq!(sma.next(&bar(4.0)), 4.0);
-        assert_eq!(sma.next(&bar(7.0)), 5.0);
-        assert_eq!(sma.next(&bar(1.0)), 4.0);
+        fn check<S: Storage>(mut sma: SimpleMovingAverageBase<S>) {
+            assert_eq!(sma.next(&bar(4.0)), 4.0);
+            assert_eq!(sma.next(&bar(4.0)), 4.0);
+            assert_eq!(sma.next(&bar(7.0)), 5.0);
+            assert_eq!(sma.next(&bar(1.0)), 4.0);
+        }
+
+        check(SimpleMovingAverage::new(3).unwrap());
+        check(InlineSimpleMovingAverage::<3>::new().unwrap());
+    }
+
+    #[test]
+    fn test_next_long_stream() {
+        let mut rng = rand::thread_rng();
+        let mut sma = SimpleMovingAverage::new(10).unwrap();
+        let mut inputs = Vec::new();
+
+        // Runs of large and small values make the rounding errors of a plain running sum visible
+        for _ in 0..10_000 {
+            let scale = [1e9, 1.0, 1e-3][rng.gen_range(0, 3)];
+            for _ in 0..rng.gen_range(1, 30) {
+                let input = rng.gen_range(0.0, scale);
+                inputs.push(input);
+
+                let window = &inputs[inputs.len().saturating_sub(10)..];
+                let expected = window.iter().sum::<f64>() / window.len() as f64;
+                assert!((sma.next(input) - expected).abs() <= expected * 1e-12);
+            }
+        }
     }
 
     #[test]
     fn test_reset() {
-        let mut sma = SimpleMovingAverage::new(4).unwrap();
-        assert_eq!(sma.next(4.0), 4.0);
-        assert_eq!(sma.next(5.0), 4.5);
-        assert_eq!(sma.next(6.0), 5.0);
+        fn check<S: Storage>(mut sma: SimpleMovingAverageBase<S>) {
+            assert_eq!(sma.next(4.0), 4.0);
+            assert_eq!(sma.next(5.0), 4.5);
+            assert_eq!(sma.next(6.0), 5.0);
+
+            sma.reset();
+            assert_eq!(sma.next(99.0), 99.0);
+        }
 
-        sma.reset();
-        assert_eq!(sma.next(99.0), 99.0);
+        check(SimpleMovingAverage::new(4).unwrap());
+        check(InlineSimpleMovingAverage::<4>::new().unwrap());
     }
 
     #[test]
     fn test_default() {
         SimpleMovingAverage::default();
+        InlineSimpleMovingAverage::<9>::default();
     }
 
     #[test]
     fn test_display() {
         let sma = SimpleMovingAverage::new(5).unwrap();
         assert_eq!(format!("{}", sma), "SMA(5)");
+
+        let sma = InlineSimpleMovingAverage::<5>::new().unwrap();
+        assert_eq!(format!("{}", sma), "SMA(5)");
     }
 }
@@ -0,0 +1,168 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Smoothed moving average (SMMA), also known as Wilder's moving average (RMA).
+///
+/// The smoothing used by J. Welles Wilder for RSI, ATR and ADX: an exponential moving
+/// average with a smoothing factor of 1 / _period_, seeded with the simple moving
+/// average of the first _period_ values.
+///
+/// # Formula
+///
+/// SMMA<sub>t</sub> = (SMMA<sub>t-1</sub> * (_period_ - 1) + p<sub>t</sub>) / _period_
+///
+/// Where:
+///
+/// * _p<sub>t</sub>_ - input value at a time period t
+///
+/// Until _period_ values are received, SMMA is the simple moving average of the values so far.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::SmoothedMovingAverage;
+/// use ta::Next;
+///
+/// let mut smma = SmoothedMovingAverage::new(3).unwrap();
+/// assert_eq!(smma.next(2.0), 2.0);
+/// assert_eq!(smma.next(5.0), 3.5);
+/// assert_eq!(smma.next(5.0), 4.0);
+/// assert_eq!(smma.next(7.0), 5.0);
+/// ```
+///
+/// # Links
+///
+/// * [Modified moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Modified_moving_average)
+///
+#[doc(alias = "SMMA")]
+#[doc(alias = "RMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct SmoothedMovingAverage {
+    period: usize,
+    count: usize,
+    current: f64,
+}
+
+impl SmoothedMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                count: 0,
+                current: 0.0,
+            }),
+        }
+    }
+}
+
+impl Period for SmoothedMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for SmoothedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        // The cumulative average of the first values is the SMA seed
+        if self.count < self.period {
+            self.count += 1;
+        }
+        self.current += (input - self.current) / self.count as f64;
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for SmoothedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for SmoothedMovingAverage {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.current = 0.0;
+    }
+}
+
+impl Default for SmoothedMovingAverage {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for SmoothedMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SMMA({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(SmoothedMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(SmoothedMovingAverage::new(0).is_err());
+        assert!(SmoothedMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut smma = SmoothedMovingAverage::new(3).unwrap();
+
+        // SMA seed
+        assert_eq!(smma.next(2.0), 2.0);
+        assert_eq!(smma.next(5.0), 3.5);
+        assert_eq!(round(smma.next(1.0)), 2.667);
+        // Wilder smoothing
+        assert_eq!(round(smma.next(6.25)), 3.861);
+        assert_eq!(round(smma.next(4.0)), 3.907);
+
+        let mut smma = SmoothedMovingAverage::new(3).unwrap();
+        let bar1 = Bar::new().close(2);
+        let bar2 = Bar::new().close(5);
+        assert_eq!(smma.next(&bar1), 2.0);
+        assert_eq!(smma.next(&bar2), 3.5);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut smma = SmoothedMovingAverage::new(3).unwrap();
+
+        assert_eq!(smma.next(2.0), 2.0);
+        assert_eq!(smma.next(5.0), 3.5);
+
+        smma.reset();
+        assert_eq!(smma.next(5.0), 5.0);
+        assert_eq!(smma.next(2.0), 3.5);
+    }
+
+    #[test]
+    fn test_default() {
+        SmoothedMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let smma = SmoothedMovingAverage::new(7).unwrap();
+        assert_eq!(format!("{}", smma), "SMMA(7)");
+    }
+}
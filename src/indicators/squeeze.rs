@@ -0,0 +1,252 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{BollingerBands, KeltnerChannel, KeltnerChannelOutput, Maximum, Minimum};
+use crate::{Close, High, Low, Next, Period, Reset, RollingWindow};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bollinger Bands squeeze with momentum histogram (TTM Squeeze).
+///
+/// Developed by John Carter, the squeeze compares Bollinger Bands with a Keltner Channel.
+/// When the volatility drops, the Bollinger Bands contract inside the Keltner Channel:
+/// the squeeze is "on" and a breakout is expected. The momentum histogram gives
+/// the likely direction of the breakout.
+///
+/// # Formula
+///
+/// See BB, KC documentation.
+///
+/// * _Squeeze_ = BB<sub>Lower Band</sub> > KC<sub>Lower Band</sub> and BB<sub>Upper Band</sub> < KC<sub>Upper Band</sub>
+/// * _Delta_ = close - ((Max(high, _period_) + Min(low, _period_)) / 2 + SMA(close, _period_)) / 2
+/// * _Momentum_ = value of the linear regression of _Delta_ over the last _period_ values
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+/// * _bb_multiplier_ - SD factor of the Bollinger Bands. Default is 2.0.
+/// * _kc_multiplier_ - ATR factor of the Keltner Channel. Default is 1.5.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Squeeze;
+/// use ta::Next;
+///
+/// let mut squeeze = Squeeze::new(3, 2.0, 1.5).unwrap();
+///
+/// let out = squeeze.next(2.0);
+/// assert_eq!(out.squeeze, false);
+/// assert_eq!(out.momentum, 0.0);
+///
+/// let out = squeeze.next(5.0);
+/// assert_eq!(out.squeeze, false);
+/// assert_eq!(out.momentum, 1.5);
+/// ```
+///
+/// # Links
+///
+/// * [TTM Squeeze, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:ttm_squeeze)
+///
+#[doc(alias = "TTM Squeeze")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Squeeze {
+    bb: BollingerBands,
+    kc: KeltnerChannel,
+    max: Maximum,
+    min: Minimum,
+    window: RollingWindow,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqueezeOutput {
+    pub squeeze: bool,
+    pub momentum: f64,
+}
+
+impl From<SqueezeOutput> for (bool, f64) {
+    fn from(squeeze: SqueezeOutput) -> Self {
+        (squeeze.squeeze, squeeze.momentum)
+    }
+}
+
+impl Squeeze {
+    pub fn new(period: usize, bb_multiplier: f64, kc_multiplier: f64) -> Result<Self> {
+        Ok(Self {
+            bb: BollingerBands::new(period, bb_multiplier)?,
+            kc: KeltnerChannel::new(period, kc_multiplier)?,
+            max: Maximum::new(period)?,
+            min: Minimum::new(period)?,
+            window: RollingWindow::new(period)?,
+        })
+    }
+
+    pub fn bb_multiplier(&self) -> f64 {
+        self.bb.multiplier()
+    }
+
+    pub fn kc_multiplier(&self) -> f64 {
+        self.kc.multiplier()
+    }
+
+    fn output(
+        &mut self,
+        kc: KeltnerChannelOutput,
+        close: f64,
+        high: f64,
+        low: f64,
+    ) -> SqueezeOutput {
+        let bb = self.bb.next(close);
+        let max = self.max.next(high);
+        let min = self.min.next(low);
+
+        let delta = close - ((max + min) / 2.0 + bb.average) / 2.0;
+        self.window.push(delta);
+
+        SqueezeOutput {
+            squeeze: bb.lower > kc.lower && bb.upper < kc.upper,
+            momentum: self.linear_regression(),
+        }
+    }
+
+    /// Returns the value of the least squares line at the newest point of the window.
+    fn linear_regression(&self) -> f64 {
+        let n = self.window.len() as f64;
+        if n < 2.0 {
+            return self.window.newest().unwrap_or(0.0);
+        }
+
+        let (sum_y, sum_xy) = self
+            .window
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(sum_y, sum_xy), (x, y)| {
+                (sum_y + y, sum_xy + x as f64 * y)
+            });
+        let sum_x = n * (n - 1.0) / 2.0;
+        let sum_x2 = (n - 1.0) * n * (2.0 * n - 1.0) / 6.0;
+
+        let slope = (n * sum_xy - sum_x * sum_y) / (n * sum_x2 - sum_x * sum_x);
+        let intercept = (sum_y - slope * sum_x) / n;
+        intercept + slope * (n - 1.0)
+    }
+}
+
+impl Period for Squeeze {
+    fn period(&self) -> usize {
+        self.bb.period()
+    }
+}
+
+impl Next<f64> for Squeeze {
+    type Output = SqueezeOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let kc = self.kc.next(input);
+        self.output(kc, input, input, input)
+    }
+}
+
+impl<T: Close + High + Low> Next<&T> for Squeeze {
+    type Output = SqueezeOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let kc = self.kc.next(input);
+        self.output(kc, input.close(), input.high(), input.low())
+    }
+}
+
+impl Reset for Squeeze {
+    fn reset(&mut self) {
+        self.bb.reset();
+        self.kc.reset();
+        self.max.reset();
+        self.min.reset();
+        self.window.clear();
+    }
+}
+
+impl Default for Squeeze {
+    fn default() -> Self {
+        Self::new(20, 2.0, 1.5).unwrap()
+    }
+}
+
+impl fmt::Display for Squeeze {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SQUEEZE({}, {}, {})",
+            self.period(),
+            self.bb_multiplier(),
+            self.kc_multiplier()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Squeeze);
+
+    fn tuple(output: SqueezeOutput) -> (bool, f64) {
+        let (squeeze, momentum) = output.into();
+        (squeeze, round(momentum))
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Squeeze::new(0, 2.0, 1.5).is_err());
+        assert!(Squeeze::new(1, 2.0, 1.5).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut squeeze = Squeeze::new(3, 2.0, 1.5).unwrap();
+
+        assert_eq!(tuple(squeeze.next(2.0)), (false, 0.0));
+        assert_eq!(tuple(squeeze.next(5.0)), (false, 1.5));
+        assert_eq!(tuple(squeeze.next(1.0)), (true, -1.028));
+        assert_eq!(tuple(squeeze.next(6.25)), (true, 1.135));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(high: f64, low: f64, close: f64) -> Bar {
+            Bar::new().high(high).low(low).close(close)
+        }
+
+        let mut squeeze = Squeeze::new(3, 2.0, 1.5).unwrap();
+
+        assert_eq!(tuple(squeeze.next(&bar(11.0, 9.0, 10.0))), (true, 0.0));
+        assert_eq!(tuple(squeeze.next(&bar(12.0, 10.0, 11.0))), (true, 0.5));
+        assert_eq!(tuple(squeeze.next(&bar(13.0, 11.0, 12.0))), (true, 1.0));
+        assert_eq!(tuple(squeeze.next(&bar(12.0, 10.0, 10.5))), (true, -0.444));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut squeeze = Squeeze::new(3, 2.0, 1.5).unwrap();
+
+        assert_eq!(tuple(squeeze.next(2.0)), (false, 0.0));
+        assert_eq!(tuple(squeeze.next(5.0)), (false, 1.5));
+
+        squeeze.reset();
+        assert_eq!(tuple(squeeze.next(5.0)), (false, 0.0));
+        assert_eq!(tuple(squeeze.next(2.0)), (false, -1.5));
+    }
+
+    #[test]
+    fn test_default() {
+        Squeeze::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let squeeze = Squeeze::new(20, 2.0, 1.5).unwrap();
+        assert_eq!(format!("{}", squeeze), "SQUEEZE(20, 2, 1.5)");
+    }
+}
@@ -1,6 +1,8 @@
 use std::fmt;
 
-use crate::errors::{Result, TaError};
+use crate::errors::Result;
+use crate::indicators::{VarianceBase, VarianceEstimator};
+use crate::storage::{Heap, Inline, Storage};
 use crate::{Close, Next, Period, Reset};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -19,19 +21,29 @@ use serde::{Deserialize, Serialize};
 /// * _N_ - number of probes in observation.
 /// * _x<sub>i</sub>_ - i-th observed value from N elements observation.
 ///
+/// With the sample estimator _N - 1_ is used instead of _N_.
+///
 /// # Parameters
 ///
 /// * _period_ - number of periods (integer greater than 0)
+/// * _estimator_ - estimator of the variance, see [VarianceEstimator]. Default is population.
+///
+/// It is the square root of the [Variance](crate::indicators::Variance), so rounding errors
+/// do not accumulate over long-running streams either.
 ///
 /// # Example
 ///
 /// ```
-/// use ta::indicators::StandardDeviation;
+/// use ta::indicators::{StandardDeviation, VarianceEstimator};
 /// use ta::Next;
 ///
 /// let mut sd = StandardDeviation::new(3).unwrap();
 /// assert_eq!(sd.next(10.0), 0.0);
 /// assert_eq!(sd.next(20.0), 5.0);
+///
+/// let mut sd = StandardDeviation::with_estimator(3, VarianceEstimator::Sample).unwrap();
+/// assert_eq!(sd.next(10.0), 0.0);
+/// assert_eq!(sd.next(20.0).round(), 7.0);
 /// ```
 ///
 /// # Links
@@ -39,78 +51,72 @@ use serde::{Deserialize, Serialize};
 /// * [Standard Deviation, Wikipedia](https://en.wikipedia.org/wiki/Standard_deviation)
 ///
 #[doc(alias = "SD")]
+pub type StandardDeviation = StandardDeviationBase<Heap>;
+
+/// Standard deviation (SD) of the last `N` values, with `N` known at compile time.
+///
+/// Behaves exactly like [StandardDeviation], but the window is stored inline
+/// and no heap allocation is performed.
+pub type InlineStandardDeviation<const N: usize> = StandardDeviationBase<Inline<N>>;
+
+/// Standard deviation (SD) generic over its [Storage].
+///
+/// See [StandardDeviation] and [InlineStandardDeviation].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct StandardDeviation {
-    period: usize,
-    index: usize,
-    count: usize,
-    m: f64,
-    m2: f64,
-    deque: Box<[f64]>,
+pub struct StandardDeviationBase<S: Storage> {
+    variance: VarianceBase<S>,
+}
+
+impl<S: Storage> StandardDeviationBase<S> {
+    pub fn estimator(&self) -> VarianceEstimator {
+        self.variance.estimator()
+    }
+
+    pub(super) fn mean(&self) -> f64 {
+        self.variance.mean()
+    }
 }
 
 impl StandardDeviation {
     pub fn new(period: usize) -> Result<Self> {
-        match period {
-            0 => Err(TaError::InvalidParameter),
-            _ => Ok(Self {
-                period,
-                index: 0,
-                count: 0,
-                m: 0.0,
-                m2: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
-            }),
-        }
+        Self::with_estimator(period, VarianceEstimator::Population)
     }
 
-    pub(super) fn mean(&self) -> f64 {
-        self.m
+    pub fn with_estimator(period: usize, estimator: VarianceEstimator) -> Result<Self> {
+        Ok(Self {
+            variance: VarianceBase::with_period(period, estimator)?,
+        })
+    }
+}
+
+impl<const N: usize> InlineStandardDeviation<N> {
+    pub fn new() -> Result<Self> {
+        Self::with_estimator(VarianceEstimator::Population)
+    }
+
+    pub fn with_estimator(estimator: VarianceEstimator) -> Result<Self> {
+        Ok(Self {
+            variance: VarianceBase::with_period(N, estimator)?,
+        })
     }
 }
 
-impl Period for StandardDeviation {
+impl<S: Storage> Period for StandardDeviationBase<S> {
     fn period(&self) -> usize {
-        self.period
+        self.variance.period()
     }
 }
 
-impl Next<f64> for StandardDeviation {
+impl<S: Storage> Next<f64> for StandardDeviationBase<S> {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        let old_val = self.deque[self.index];
-        self.deque[self.index] = input;
-
-        self.index = if self.index + 1 < self.period {
-            self.index + 1
-        } else {
-            0
-        };
-
-        if self.count < self.period {
-            self.count += 1;
-            let delta = input - self.m;
-            self.m += delta / self.count as f64;
-            let delta2 = input - self.m;
-            self.m2 += delta * delta2;
-        } else {
-            let delta = input - old_val;
-            let old_m = self.m;
-            self.m += delta / self.period as f64;
-            let delta2 = input - self.m + old_val - old_m;
-            self.m2 += delta * delta2;
-        }
-        if self.m2 < 0.0 {
-            self.m2 = 0.0;
-        }
-
-        (self.m2 / self.count as f64).sqrt()
+        self.variance.next(input).sqrt()
     }
 }
 
-impl<T: Close> Next<&T> for StandardDeviation {
+impl<S: Storage, T: Close> Next<&T> for StandardDeviationBase<S> {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
@@ -118,15 +124,9 @@ impl<T: Close> Next<&T> for StandardDeviation {
     }
 }
 
-impl Reset for StandardDeviation {
+impl<S: Storage> Reset for StandardDeviationBase<S> {
     fn reset(&mut self) {
-        self.index = 0;
-        self.count = 0;
-        self.m = 0.0;
-        self.m2 = 0.0;
-        for i in 0..self.period {
-            self.deque[i] = 0.0;
-        }
+        self.variance.reset();
     }
 }
 
@@ -136,9 +136,15 @@ impl Default for StandardDeviation {
     }
 }
 
-impl fmt::Display for StandardDeviation {
+impl<const N: usize> Default for InlineStandardDeviation<N> {
+    fn default() -> Self {
+        Self::new().unwrap()
+    }
+}
+
+impl<S: Storage> fmt::Display for StandardDeviationBase<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "SD({})", self.period)
+        write!(f, "SD({})", self.period())
     }
 }
 
@@ -146,36 +152,71 @@ impl fmt::Display for StandardDeviation {
 mod tests {
     use super::*;
     use crate::test_helper::*;
+    use rand::Rng;
 
     test_indicator!(StandardDeviation);
 
+    mod inline {
+        use super::*;
+
+        type InlineSd = InlineStandardDeviation<9>;
+
+        test_indicator!(InlineSd);
+    }
+
     #[test]
     fn test_new() {
         assert!(StandardDeviation::new(0).is_err());
         assert!(StandardDeviation::new(1).is_ok());
+        assert!(InlineStandardDeviation::<0>::new().is_err());
+        assert!(InlineStandardDeviation::<1>::new().is_ok());
     }
 
     #[test]
     fn test_next() {
-        let mut sd = StandardDeviation::new(4).unwrap();
-        assert_eq!(sd.next(10.0), 0.0);
-        assert_eq!(sd.next(20.0), 5.0);
-        assert_eq!(round(sd.next(30.0)), 8.165);
-        assert_eq!(round(sd.next(20.0)), 7.071);
-        assert_eq!(round(sd.next(10.0)), 7.071);
-        assert_eq!(round(sd.next(100.0)), 35.355);
+        fn check<S: Storage>(mut sd: StandardDeviationBase<S>) {
+            assert_eq!(sd.next(10.0), 0.0);
+            assert_eq!(sd.next(20.0), 5.0);
+            assert_eq!(round(sd.next(30.0)), 8.165);
+            assert_eq!(round(sd.next(20.0)), 7.071);
+            assert_eq!(round(sd.next(10.0)), 7.071);
+            assert_eq!(round(sd.next(100.0)), 35.355);
+        }
+
+        check(StandardDeviation::new(4).unwrap());
+        check(InlineStandardDeviation::<4>::new().unwrap());
+    }
+
+    #[test]
+    fn test_next_sample() {
+        fn check<S: Storage>(mut sd: StandardDeviationBase<S>) {
+            assert_eq!(sd.estimator(), VarianceEstimator::Sample);
+            assert_eq!(sd.next(10.0), 0.0);
+            assert_eq!(round(sd.next(20.0)), 7.071);
+            assert_eq!(sd.next(30.0), 10.0);
+            assert_eq!(round(sd.next(20.0)), 8.165);
+            assert_eq!(round(sd.next(10.0)), 8.165);
+            assert_eq!(round(sd.next(100.0)), 40.825);
+        }
+
+        check(StandardDeviation::with_estimator(4, VarianceEstimator::Sample).unwrap());
+        check(InlineStandardDeviation::<4>::with_estimator(VarianceEstimator::Sample).unwrap());
     }
 
     #[test]
     fn test_next_floating_point_error() {
-        let mut sd = StandardDeviation::new(6).unwrap();
-        assert_eq!(sd.next(1.872), 0.0);
-        assert_eq!(round(sd.next(1.0)), 0.436);
-        assert_eq!(round(sd.next(1.0)), 0.411);
-        assert_eq!(round(sd.next(1.0)), 0.378);
-        assert_eq!(round(sd.next(1.0)), 0.349);
-        assert_eq!(round(sd.next(1.0)), 0.325);
-        assert_eq!(round(sd.next(1.0)), 0.0);
+        fn check<S: Storage>(mut sd: StandardDeviationBase<S>) {
+            assert_eq!(sd.next(1.872), 0.0);
+            assert_eq!(round(sd.next(1.0)), 0.436);
+            assert_eq!(round(sd.next(1.0)), 0.411);
+            assert_eq!(round(sd.next(1.0)), 0.378);
+            assert_eq!(round(sd.next(1.0)), 0.349);
+            assert_eq!(round(sd.next(1.0)), 0.325);
+            assert_eq!(round(sd.next(1.0)), 0.0);
+        }
+
+        check(StandardDeviation::new(6).unwrap());
+        check(InlineStandardDeviation::<6>::new().unwrap());
     }
 
     #[test]
@@ -184,43 +225,82 @@ mod tests {
             Bar::new().close(close)
         }
 
-        let mut sd = StandardDeviation::new(4).unwrap();
-        assert_eq!(sd.next(&bar(10.0)), 0.0);
-        assert_eq!(sd.next(&bar(20.0)), 5.0);
-        assert_eq!(round(sd.next(&bar(30.0))), 8.165);
-        assert_eq!(round(sd.next(&bar(20.0))), 7.071);
-        assert_eq!(round(sd.next(&bar(10.0))), 7.071);
-        assert_eq!(round(sd.next(&bar(100.0))), 35.355);
+        fn check<S: Storage>(mut sd: StandardDeviationBase<S>) {
+            assert_eq!(sd.next(&bar(10.0)), 0.0);
+            assert_eq!(sd.next(&bar(20.0)), 5.0);
+            assert_eq!(round(sd.next(&bar(30.0))), 8.165);
+            assert_eq!(round(sd.next(&bar(20.0))), 7.071);
+            assert_eq!(round(sd.next(&bar(10.0))), 7.071);
+            assert_eq!(round(sd.next(&bar(100.0))), 35.355);
+        }
+
+        check(StandardDeviation::new(4).unwrap());
+        check(InlineStandardDeviation::<4>::new().unwrap());
     }
 
     #[test]
     fn test_next_same_values() {
-        let mut sd = StandardDeviation::new(3).unwrap();
-        assert_eq!(sd.next(4.2), 0.0);
-        assert_eq!(sd.next(4.2), 0.0);
-        assert_eq!(sd.next(4.2), 0.0);
-        assert_eq!(sd.next(4.2), 0.0);
+        fn check<S: Storage>(mut sd: StandardDeviationBase<S>) {
+            assert_eq!(sd.next(4.2), 0.0);
+            assert_eq!(sd.next(4.2), 0.0);
+            assert_eq!(sd.next(4.2), 0.0);
+            assert_eq!(sd.next(4.2), 0.0);
+        }
+
+        check(StandardDeviation::new(3).unwrap());
+        check(InlineStandardDeviation::<3>::new().unwrap());
+    }
+
+    #[test]
+    fn test_next_long_stream() {
+        let mut rng = rand::thread_rng();
+        let mut sd = StandardDeviation::new(10).unwrap();
+        let mut inputs = Vec::new();
+
+        // Runs of large and small values make the rounding errors of the incremental update visible
+        for _ in 0..10_000 {
+            let scale = [1e9, 1.0, 1e-3][rng.gen_range(0, 3)];
+            for _ in 0..rng.gen_range(1, 30) {
+                let input = rng.gen_range(0.0, scale);
+                inputs.push(input);
+
+                let window = &inputs[inputs.len().saturating_sub(10)..];
+                let count = window.len() as f64;
+                let mean = window.iter().sum::<f64>() / count;
+                let variance = window.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count;
+                let expected = variance.sqrt();
+                assert!((sd.next(input) - expected).abs() <= expected * 1e-9);
+            }
+        }
     }
 
     #[test]
     fn test_reset() {
-        let mut sd = StandardDeviation::new(4).unwrap();
-        assert_eq!(sd.next(10.0), 0.0);
-        assert_eq!(sd.next(20.0), 5.0);
-        assert_eq!(round(sd.next(30.0)), 8.165);
+        fn check<S: Storage>(mut sd: StandardDeviationBase<S>) {
+            assert_eq!(sd.next(10.0), 0.0);
+            assert_eq!(sd.next(20.0), 5.0);
+            assert_eq!(round(sd.next(30.0)), 8.165);
 
-        sd.reset();
-        assert_eq!(sd.next(20.0), 0.0);
+            sd.reset();
+            assert_eq!(sd.next(20.0), 0.0);
+        }
+
+        check(StandardDeviation::new(4).unwrap());
+        check(InlineStandardDeviation::<4>::new().unwrap());
     }
 
     #[test]
     fn test_default() {
         StandardDeviation::default();
+        InlineStandardDeviation::<9>::default();
     }
 
     #[test]
     fn test_display() {
         let sd = StandardDeviation::new(5).unwrap();
         assert_eq!(format!("{}", sd), "SD(5)");
+
+        let sd = InlineStandardDeviation::<5>::new().unwrap();
+        assert_eq!(format!("{}", sd), "SD(5)");
     }
 }
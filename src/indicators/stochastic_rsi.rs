@@ -0,0 +1,224 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::moving_average::MovingAverage;
+use crate::indicators::{FastStochastic, MovingAverageType, RelativeStrengthIndex};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Stochastic RSI (StochRSI).
+///
+/// Developed by Tushar Chande and Stanley Kroll, StochRSI applies the stochastic
+/// formula to the values of the [relative strength index](struct.RelativeStrengthIndex.html)
+/// instead of prices. It shows where the RSI stands relative to its own range over
+/// the last _period_ values, which makes it more sensitive than the RSI itself.
+///
+/// # Formula
+///
+/// * _StochRSI_ = (RSI - Min(RSI, _period_)) / (Max(RSI, _period_) - Min(RSI, _period_)) * 100
+/// * _%K_ = SMA(_StochRSI_, _k_period_)
+/// * _%D_ = SMA(_%K_, _d_period_)
+///
+/// When the RSI range is empty, _StochRSI_ is 50. With _k_period_ and _d_period_ of 1
+/// no smoothing is applied.
+///
+/// # Parameters
+///
+/// * _rsi_period_ - number of periods of RSI (integer greater than 0). Default is 14.
+/// * _period_ - number of RSI values of the stochastic (integer greater than 0). Default is 14.
+/// * _k_period_ - smoothing period of %K (integer greater than 0). Default is 3.
+/// * _d_period_ - period of %D (integer greater than 0). Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::StochasticRsi;
+/// use ta::Next;
+///
+/// let mut stoch_rsi = StochasticRsi::new(3, 3, 1, 1).unwrap();
+/// assert_eq!(stoch_rsi.next(10.0).k, 50.0);
+/// assert_eq!(stoch_rsi.next(10.5).k, 100.0);
+/// assert_eq!(stoch_rsi.next(10.0).k, 0.0);
+/// ```
+///
+/// # Links
+///
+/// * [Stochastic RSI, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:stochrsi)
+///
+#[doc(alias = "StochRSI")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct StochasticRsi {
+    rsi: RelativeStrengthIndex,
+    fast_stochastic: FastStochastic,
+    k: MovingAverage,
+    d: MovingAverage,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StochasticRsiOutput {
+    pub k: f64,
+    pub d: f64,
+}
+
+impl From<StochasticRsiOutput> for (f64, f64) {
+    fn from(stoch_rsi: StochasticRsiOutput) -> Self {
+        (stoch_rsi.k, stoch_rsi.d)
+    }
+}
+
+impl StochasticRsi {
+    pub fn new(rsi_period: usize, period: usize, k_period: usize, d_period: usize) -> Result<Self> {
+        Ok(Self {
+            rsi: RelativeStrengthIndex::new(rsi_period)?,
+            fast_stochastic: FastStochastic::new(period)?,
+            k: MovingAverage::new(MovingAverageType::Simple, k_period)?,
+            d: MovingAverage::new(MovingAverageType::Simple, d_period)?,
+        })
+    }
+
+    pub fn rsi_period(&self) -> usize {
+        self.rsi.period()
+    }
+}
+
+impl Period for StochasticRsi {
+    fn period(&self) -> usize {
+        self.fast_stochastic.period()
+    }
+}
+
+impl Next<f64> for StochasticRsi {
+    type Output = StochasticRsiOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let rsi = self.rsi.next(input);
+        let k = self.k.next(self.fast_stochastic.next(rsi));
+        let d = self.d.next(k);
+        StochasticRsiOutput { k, d }
+    }
+}
+
+impl<T: Close> Next<&T> for StochasticRsi {
+    type Output = StochasticRsiOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for StochasticRsi {
+    fn reset(&mut self) {
+        self.rsi.reset();
+        self.fast_stochastic.reset();
+        self.k.reset();
+        self.d.reset();
+    }
+}
+
+impl Default for StochasticRsi {
+    fn default() -> Self {
+        Self::new(14, 14, 3, 3).unwrap()
+    }
+}
+
+impl fmt::Display for StochasticRsi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "STOCH_RSI({}, {}, {}, {})",
+            self.rsi.period(),
+            self.fast_stochastic.period(),
+            self.k.period(),
+            self.d.period()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(StochasticRsi);
+
+    fn tuple(output: StochasticRsiOutput) -> (f64, f64) {
+        let (k, d) = output.into();
+        (round(k), round(d))
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(StochasticRsi::new(0, 1, 1, 1).is_err());
+        assert!(StochasticRsi::new(1, 0, 1, 1).is_err());
+        assert!(StochasticRsi::new(1, 1, 0, 1).is_err());
+        assert!(StochasticRsi::new(1, 1, 1, 0).is_err());
+        assert!(StochasticRsi::new(1, 1, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+
+        let test_data = vec![
+            // input, k, d
+            (10.0, 50.0, 50.0), // rsi = 50
+            (10.5, 75.0, 62.5), // rsi = 85.714
+            (10.0, 50.0, 62.5), // rsi = 35.294
+            (9.5, 0.0, 25.0),   // rsi = 16.216
+            (10.2, 50.0, 25.0), // rsi = 66.667
+            (10.8, 100.0, 75.0),
+            (10.4, 50.0, 75.0),
+            (11.0, 40.698, 45.349),
+        ];
+
+        for (input, k, d) in test_data {
+            assert_eq!(tuple(stoch_rsi.next(input)), (k, d));
+        }
+    }
+
+    #[test]
+    fn test_next_without_smoothing() {
+        let mut stoch_rsi = StochasticRsi::new(3, 3, 1, 1).unwrap();
+
+        for (input, k) in [(10.0, 50.0), (10.5, 100.0), (10.0, 0.0), (9.5, 0.0)] {
+            assert_eq!(tuple(stoch_rsi.next(input)), (k, k));
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+        assert_eq!(tuple(stoch_rsi.next(&bar(10.0))), (50.0, 50.0));
+        assert_eq!(tuple(stoch_rsi.next(&bar(10.5))), (75.0, 62.5));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stoch_rsi = StochasticRsi::new(3, 3, 2, 2).unwrap();
+        assert_eq!(tuple(stoch_rsi.next(10.0)), (50.0, 50.0));
+        assert_eq!(tuple(stoch_rsi.next(10.5)), (75.0, 62.5));
+
+        stoch_rsi.reset();
+        assert_eq!(tuple(stoch_rsi.next(10.0)), (50.0, 50.0));
+        assert_eq!(tuple(stoch_rsi.next(10.5)), (75.0, 62.5));
+    }
+
+    #[test]
+    fn test_default() {
+        StochasticRsi::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let stoch_rsi = StochasticRsi::new(14, 10, 3, 5).unwrap();
+        assert_eq!(format!("{}", stoch_rsi), "STOCH_RSI(14, 10, 3, 5)");
+        assert_eq!(stoch_rsi.rsi_period(), 14);
+        assert_eq!(stoch_rsi.period(), 10);
+    }
+}
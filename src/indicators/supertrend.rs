@@ -0,0 +1,240 @@
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+use crate::indicators::{AverageTrueRange, Direction};
+use crate::{Close, High, Low, Next, Period, Reset};
+
+/// Supertrend.
+///
+/// Developed by Olivier Seban, Supertrend is a trend following overlay drawn below prices
+/// during an uptrend and above prices during a downtrend. The distance of the bands from
+/// the median price is a multiple of the Average True Range (ATR).
+///
+/// # Formula
+///
+/// * _Basic Upper Band_ = (high + low) / 2 + ATR(_period_) * _multiplier_
+/// * _Basic Lower Band_ = (high + low) / 2 - ATR(_period_) * _multiplier_
+///
+/// The final upper band only moves down, unless the previous close is above the previous
+/// final upper band, and the final lower band only moves up, unless the previous close is
+/// below the previous final lower band:
+///
+/// * _Upper Band_ = Basic Upper Band if Basic Upper Band < Previous Upper Band or
+///   Previous Close > Previous Upper Band, Previous Upper Band otherwise
+/// * _Lower Band_ = Basic Lower Band if Basic Lower Band > Previous Lower Band or
+///   Previous Close < Previous Lower Band, Previous Lower Band otherwise
+///
+/// In an uptrend the direction turns [Short](Direction::Short) when the close falls below
+/// the lower band, in a downtrend it turns [Long](Direction::Long) when the close rises above
+/// the upper band. The Supertrend is the lower band in an uptrend and the upper band in a
+/// downtrend. The initial direction is long.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of ATR (integer greater than 0). Default is 10.
+/// * _multiplier_ - ATR factor. Default is 3.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Direction, Supertrend};
+/// use ta::{DataItem, Next};
+///
+/// let mut supertrend = Supertrend::new(3, 1.0).unwrap();
+///
+/// let bar = DataItem::builder()
+///     .open(9.0).high(10.0).low(9.0).close(9.5).volume(1.0).build().unwrap();
+/// let out = supertrend.next(&bar);
+/// assert_eq!(out.value, 8.5);
+/// assert_eq!(out.direction, Direction::Long);
+/// assert_eq!(out.flipped, false);
+/// ```
+///
+/// # Links
+///
+/// * [Supertrend, TradingView](https://www.tradingview.com/support/solutions/43000634738-supertrend/)
+///
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Supertrend {
+    atr: AverageTrueRange,
+    multiplier: f64,
+    direction: Direction,
+    // Close, upper band and lower band of the previous bar
+    previous: Option<(f64, f64, f64)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SupertrendOutput {
+    /// Value of the active band: the lower band in an uptrend, the upper band in a downtrend.
+    pub value: f64,
+    pub direction: Direction,
+    /// Whether the direction changed on this bar.
+    pub flipped: bool,
+}
+
+impl From<SupertrendOutput> for (f64, Direction, bool) {
+    fn from(supertrend: SupertrendOutput) -> Self {
+        (supertrend.value, supertrend.direction, supertrend.flipped)
+    }
+}
+
+impl Supertrend {
+    pub fn new(period: usize, multiplier: f64) -> Result<Self> {
+        Ok(Self {
+            atr: AverageTrueRange::new(period)?,
+            multiplier,
+            direction: Direction::Long,
+            previous: None,
+        })
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+}
+
+impl Period for Supertrend {
+    fn period(&self) -> usize {
+        self.atr.period()
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for Supertrend {
+    type Output = SupertrendOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let atr = self.atr.next(input) * self.multiplier;
+        let median = (input.high() + input.low()) / 2.0;
+        let close = input.close();
+
+        let mut upper = median + atr;
+        let mut lower = median - atr;
+        let mut flipped = false;
+
+        if let Some((prev_close, prev_upper, prev_lower)) = self.previous {
+            if upper > prev_upper && prev_close <= prev_upper {
+                upper = prev_upper;
+            }
+            if lower < prev_lower && prev_close >= prev_lower {
+                lower = prev_lower;
+            }
+
+            let direction = match self.direction {
+                Direction::Long if close < lower => Direction::Short,
+                Direction::Short if close > upper => Direction::Long,
+                direction => direction,
+            };
+            flipped = direction != self.direction;
+            self.direction = direction;
+        }
+        self.previous = Some((close, upper, lower));
+
+        let value = match self.direction {
+            Direction::Long => lower,
+            Direction::Short => upper,
+        };
+
+        SupertrendOutput {
+            value,
+            direction: self.direction,
+            flipped,
+        }
+    }
+}
+
+impl Reset for Supertrend {
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.direction = Direction::Long;
+        self.previous = None;
+    }
+}
+
+impl Default for Supertrend {
+    fn default() -> Self {
+        Self::new(10, 3.0).unwrap()
+    }
+}
+
+impl fmt::Display for Supertrend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SUPERTREND({}, {})", self.period(), self.multiplier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    fn bar(high: f64, low: f64, close: f64) -> Bar {
+        Bar::new().high(high).low(low).close(close)
+    }
+
+    fn tuple(output: SupertrendOutput) -> (f64, Direction, bool) {
+        let (value, direction, flipped) = output.into();
+        (round(value), direction, flipped)
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Supertrend::new(0, 3.0).is_err());
+        assert!(Supertrend::new(1, 3.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        use Direction::{Long, Short};
+
+        let mut supertrend = Supertrend::new(3, 1.0).unwrap();
+
+        let test_data = vec![
+            // high, low, close, supertrend, direction, flipped
+            (10.0, 9.0, 9.5, 8.5, Long, false),
+            (10.5, 9.5, 10.2, 9.0, Long, false),
+            (11.0, 10.0, 10.8, 9.5, Long, false),
+            (11.2, 10.4, 10.6, 9.9, Long, false),
+            (10.6, 9.6, 9.8, 11.05, Short, true),
+            (9.8, 8.6, 8.8, 10.275, Short, false),
+            (9.0, 8.0, 8.2, 9.538, Short, false),
+            (9.4, 8.4, 9.3, 9.538, Short, false),
+            (10.4, 9.4, 10.2, 8.791, Long, true),
+            (11.0, 10.0, 10.9, 9.445, Long, false),
+        ];
+
+        for (high, low, close, value, direction, flipped) in test_data {
+            assert_eq!(
+                tuple(supertrend.next(&bar(high, low, close))),
+                (value, direction, flipped)
+            );
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut supertrend = Supertrend::new(3, 1.0).unwrap();
+
+        supertrend.next(&bar(11.2, 10.4, 10.6));
+        let out = supertrend.next(&bar(10.6, 9.6, 5.0));
+        assert_eq!(out.direction, Direction::Short);
+
+        supertrend.reset();
+        let out = supertrend.next(&bar(10.0, 9.0, 9.5));
+        assert_eq!(tuple(out), (8.5, Direction::Long, false));
+    }
+
+    #[test]
+    fn test_default() {
+        Supertrend::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = Supertrend::new(10, 3.0).unwrap();
+        assert_eq!(format!("{}", indicator), "SUPERTREND(10, 3)");
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Tillson T3 moving average (T3).
+///
+/// Developed by Tim Tillson, T3 chains six [EMAs](struct.ExponentialMovingAverage.html)
+/// and combines the last four of them, which gives a smooth average with little lag.
+/// The _volume factor_ sets the balance: 0 gives the triple smoothed EMA,
+/// greater values reduce the lag at the cost of some overshoot.
+///
+/// # Formula
+///
+/// T3<sub>t</sub> = c<sub>1</sub> * e<sub>6</sub> + c<sub>2</sub> * e<sub>5</sub> + c<sub>3</sub> * e<sub>4</sub> + c<sub>4</sub> * e<sub>3</sub>
+///
+/// Where:
+///
+/// * _e<sub>1</sub>_ = EMA(price, _period_), _e<sub>n</sub>_ = EMA(e<sub>n-1</sub>, _period_)
+/// * _c<sub>1</sub>_ = -a<sup>3</sup>
+/// * _c<sub>2</sub>_ = 3a<sup>2</sup> + 3a<sup>3</sup>
+/// * _c<sub>3</sub>_ = -6a<sup>2</sup> - 3a - 3a<sup>3</sup>
+/// * _c<sub>4</sub>_ = 1 + 3a + a<sup>3</sup> + 3a<sup>2</sup>
+/// * _a_ - volume factor
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the EMAs (integer greater than 0). Default is 5.
+/// * _volume_factor_ - number between 0 and 1. Default is 0.7.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::T3MovingAverage;
+/// use ta::Next;
+///
+/// let mut t3 = T3MovingAverage::with_volume_factor(3, 0.0).unwrap();
+/// assert_eq!(t3.next(10.0), 10.0);
+/// assert_eq!(t3.next(13.0), 10.375);
+/// ```
+///
+/// # Links
+///
+/// * [T3 Moving Average, Technical Analysis of Stocks & Commodities](https://store.traders.com/-v16-c01-smoothi-pdf.html)
+///
+#[doc(alias = "T3")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct T3MovingAverage {
+    volume_factor: f64,
+    emas: [Ema; 6],
+}
+
+impl T3MovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_volume_factor(period, 0.7)
+    }
+
+    pub fn with_volume_factor(period: usize, volume_factor: f64) -> Result<Self> {
+        if !(0.0..=1.0).contains(&volume_factor) {
+            return Err(TaError::InvalidParameter);
+        }
+
+        let ema = Ema::new(period)?;
+        Ok(Self {
+            volume_factor,
+            emas: [
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema,
+            ],
+        })
+    }
+
+    pub fn volume_factor(&self) -> f64 {
+        self.volume_factor
+    }
+}
+
+impl Period for T3MovingAverage {
+    fn period(&self) -> usize {
+        self.emas[0].period()
+    }
+}
+
+impl Next<f64> for T3MovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let mut e = [0.0; 6];
+        let mut value = input;
+        for (ema, e) in self.emas.iter_mut().zip(e.iter_mut()) {
+            value = ema.next(value);
+            *e = value;
+        }
+
+        let a = self.volume_factor;
+        let c1 = -a.powi(3);
+        let c2 = 3.0 * a.powi(2) + 3.0 * a.powi(3);
+        let c3 = -6.0 * a.powi(2) - 3.0 * a - 3.0 * a.powi(3);
+        let c4 = 1.0 + 3.0 * a + a.powi(3) + 3.0 * a.powi(2);
+
+        c1 * e[5] + c2 * e[4] + c3 * e[3] + c4 * e[2]
+    }
+}
+
+impl<T: Close> Next<&T> for T3MovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for T3MovingAverage {
+    fn reset(&mut self) {
+        for ema in self.emas.iter_mut() {
+            ema.reset();
+        }
+    }
+}
+
+impl Default for T3MovingAverage {
+    fn default() -> Self {
+        Self::new(5).unwrap()
+    }
+}
+
+impl fmt::Display for T3MovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "T3({}, {})", self.period(), self.volume_factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(T3MovingAverage);
+
+    const INPUTS: [f64; 8] = [10.0, 13.0, 16.0, 14.0, 12.0, 15.0, 18.0, 17.0];
+
+    #[test]
+    fn test_new() {
+        assert!(T3MovingAverage::new(0).is_err());
+        assert!(T3MovingAverage::new(1).is_ok());
+        assert!(T3MovingAverage::with_volume_factor(3, -0.1).is_err());
+        assert!(T3MovingAverage::with_volume_factor(3, 1.1).is_err());
+        assert!(T3MovingAverage::with_volume_factor(3, f64::NAN).is_err());
+        assert!(T3MovingAverage::with_volume_factor(3, 0.0).is_ok());
+        assert!(T3MovingAverage::with_volume_factor(3, 1.0).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut t3 = T3MovingAverage::new(3).unwrap();
+        assert_eq!(t3.volume_factor(), 0.7);
+
+        let expected = [10.0, 10.923, 12.87, 13.993, 13.778, 14.049, 15.425, 16.496];
+        for (input, expected) in INPUTS.iter().zip(expected.iter()) {
+            assert_eq!(round(t3.next(*input)), *expected);
+        }
+    }
+
+    #[test]
+    fn test_next_without_volume_factor() {
+        // With a volume factor of 0, T3 is the triple smoothed EMA
+        let mut t3 = T3MovingAverage::with_volume_factor(3, 0.0).unwrap();
+
+        let expected = [10.0, 10.375, 11.313, 12.188, 12.594, 13.039, 13.887, 14.75];
+        for (input, expected) in INPUTS.iter().zip(expected.iter()) {
+            assert_eq!(round(t3.next(*input)), *expected);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut t3 = T3MovingAverage::new(3).unwrap();
+
+        assert_eq!(round(t3.next(&Bar::new().close(10))), 10.0);
+        assert_eq!(round(t3.next(&Bar::new().close(13))), 10.923);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut t3 = T3MovingAverage::new(3).unwrap();
+
+        assert_eq!(round(t3.next(10.0)), 10.0);
+        assert_eq!(round(t3.next(13.0)), 10.923);
+
+        t3.reset();
+        assert_eq!(round(t3.next(13.0)), 13.0);
+    }
+
+    #[test]
+    fn test_default() {
+        T3MovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let t3 = T3MovingAverage::new(5).unwrap();
+        assert_eq!(format!("{}", t3), "T3(5, 0.7)");
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Triple exponential moving average (TEMA).
+///
+/// Developed by Patrick Mulloy, TEMA reduces the lag of an
+/// [EMA](struct.ExponentialMovingAverage.html) even more than
+/// [DEMA](struct.DoubleExponentialMovingAverage.html) by combining the EMA,
+/// the EMA of the EMA and the EMA of the latter.
+///
+/// # Formula
+///
+/// TEMA<sub>t</sub> = 3 * EMA<sub>t</sub> - 3 * EMA(EMA)<sub>t</sub> + EMA(EMA(EMA))<sub>t</sub>
+///
+/// Where:
+///
+/// * _EMA_ - [exponential moving average](struct.ExponentialMovingAverage.html) with _period_
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the EMAs (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::TripleExponentialMovingAverage;
+/// use ta::Next;
+///
+/// let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+/// assert_eq!(tema.next(2.0), 2.0);
+/// assert_eq!(tema.next(5.0), 4.625);
+/// assert_eq!(tema.next(1.0), 1.6875);
+/// ```
+///
+/// # Links
+///
+/// * [Triple exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Triple_exponential_moving_average)
+///
+#[doc(alias = "TEMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct TripleExponentialMovingAverage {
+    ema1: Ema,
+    ema2: Ema,
+    ema3: Ema,
+}
+
+impl TripleExponentialMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema1: Ema::new(period)?,
+            ema2: Ema::new(period)?,
+            ema3: Ema::new(period)?,
+        })
+    }
+}
+
+impl Period for TripleExponentialMovingAverage {
+    fn period(&self) -> usize {
+        self.ema1.period()
+    }
+}
+
+impl Next<f64> for TripleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let ema1 = self.ema1.next(input);
+        let ema2 = self.ema2.next(ema1);
+        let ema3 = self.ema3.next(ema2);
+        3.0 * ema1 - 3.0 * ema2 + ema3
+    }
+}
+
+impl<T: Close> Next<&T> for TripleExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for TripleExponentialMovingAverage {
+    fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+    }
+}
+
+impl Default for TripleExponentialMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for TripleExponentialMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TEMA({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(TripleExponentialMovingAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(TripleExponentialMovingAverage::new(0).is_err());
+        assert!(TripleExponentialMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+
+        assert_eq!(tema.next(2.0), 2.0);
+        assert_eq!(tema.next(5.0), 4.625);
+        assert_eq!(tema.next(1.0), 1.6875);
+        assert_eq!(tema.next(6.25), 5.53125);
+        assert_eq!(round(tema.next(4.0)), 4.453);
+        assert_eq!(round(tema.next(3.0)), 3.211);
+
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+        let bar1 = Bar::new().close(2);
+        let bar2 = Bar::new().close(5);
+        assert_eq!(tema.next(&bar1), 2.0);
+        assert_eq!(tema.next(&bar2), 4.625);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut tema = TripleExponentialMovingAverage::new(3).unwrap();
+
+        assert_eq!(tema.next(2.0), 2.0);
+        assert_eq!(tema.next(5.0), 4.625);
+
+        tema.reset();
+        assert_eq!(tema.next(5.0), 5.0);
+    }
+
+    #[test]
+    fn test_default() {
+        TripleExponentialMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let tema = TripleExponentialMovingAverage::new(7).unwrap();
+        assert_eq!(format!("{}", tema), "TEMA(7)");
+    }
+}
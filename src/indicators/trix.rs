@@ -0,0 +1,236 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{ExponentialMovingAverage as Ema, RateOfChange};
+use crate::{Close, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Triple exponential average oscillator (TRIX).
+///
+/// Developed by Jack Hutson, TRIX is the 1-period percent rate of change of a triple
+/// smoothed [EMA](struct.ExponentialMovingAverage.html). The triple smoothing filters out
+/// price movements that are insignificant for the trend. An EMA of TRIX can be added as
+/// a signal line.
+///
+/// # Formula
+///
+/// * _TRIX_ = ROC(EMA(EMA(EMA(price, _period_), _period_), _period_), 1)
+/// * _Signal_ = EMA(_TRIX_, _signal_period_)
+///
+/// Where:
+///
+/// * _ROC_ - [rate of change](struct.RateOfChange.html), 0 for the first input
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the EMAs (integer greater than 0). Default is 15.
+/// * _signal_period_ - optional number of periods of the signal line EMA (integer greater than 0).
+///   Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::Trix;
+/// use ta::Next;
+///
+/// let mut trix = Trix::new(3).unwrap();
+/// assert_eq!(trix.next(2.0).trix, 0.0);
+/// assert_eq!(trix.next(5.0).trix, 18.75);
+///
+/// let mut trix = Trix::with_signal(3, 2).unwrap();
+/// let out = trix.next(2.0);
+/// assert_eq!((out.trix, out.signal), (0.0, Some(0.0)));
+/// let out = trix.next(5.0);
+/// assert_eq!((out.trix, out.signal), (18.75, Some(12.5)));
+/// ```
+///
+/// # Links
+///
+/// * [TRIX, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:trix)
+///
+#[doc(alias = "TRIX")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Trix {
+    ema1: Ema,
+    ema2: Ema,
+    ema3: Ema,
+    roc: RateOfChange,
+    signal: Option<Ema>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrixOutput {
+    pub trix: f64,
+    /// Signal line, if a signal period is set.
+    pub signal: Option<f64>,
+}
+
+impl From<TrixOutput> for (f64, Option<f64>) {
+    fn from(trix: TrixOutput) -> Self {
+        (trix.trix, trix.signal)
+    }
+}
+
+impl Trix {
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_optional_signal(period, None)
+    }
+
+    pub fn with_signal(period: usize, signal_period: usize) -> Result<Self> {
+        Self::with_optional_signal(period, Some(Ema::new(signal_period)?))
+    }
+
+    fn with_optional_signal(period: usize, signal: Option<Ema>) -> Result<Self> {
+        Ok(Self {
+            ema1: Ema::new(period)?,
+            ema2: Ema::new(period)?,
+            ema3: Ema::new(period)?,
+            roc: RateOfChange::new(1)?,
+            signal,
+        })
+    }
+
+    pub fn signal_period(&self) -> Option<usize> {
+        self.signal.as_ref().map(|signal| signal.period())
+    }
+}
+
+impl Period for Trix {
+    fn period(&self) -> usize {
+        self.ema1.period()
+    }
+}
+
+impl Next<f64> for Trix {
+    type Output = TrixOutput;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let ema1 = self.ema1.next(input);
+        let ema2 = self.ema2.next(ema1);
+        let ema3 = self.ema3.next(ema2);
+        let trix = self.roc.next(ema3);
+
+        TrixOutput {
+            trix,
+            signal: self.signal.as_mut().map(|signal| signal.next(trix)),
+        }
+    }
+}
+
+impl<T: Close> Next<&T> for Trix {
+    type Output = TrixOutput;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for Trix {
+    fn reset(&mut self) {
+        self.ema1.reset();
+        self.ema2.reset();
+        self.ema3.reset();
+        self.roc.reset();
+        if let Some(signal) = self.signal.as_mut() {
+            signal.reset();
+        }
+    }
+}
+
+impl Default for Trix {
+    fn default() -> Self {
+        Self::with_signal(15, 9).unwrap()
+    }
+}
+
+impl fmt::Display for Trix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.signal_period() {
+            Some(signal_period) => write!(f, "TRIX({}, {})", self.period(), signal_period),
+            None => write!(f, "TRIX({})", self.period()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Trix);
+
+    fn tuple(output: TrixOutput) -> (f64, Option<f64>) {
+        let (trix, signal) = output.into();
+        (round(trix), signal.map(round))
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Trix::new(0).is_err());
+        assert!(Trix::new(1).is_ok());
+        assert!(Trix::with_signal(0, 1).is_err());
+        assert!(Trix::with_signal(1, 0).is_err());
+        assert!(Trix::with_signal(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut trix = Trix::new(3).unwrap();
+        assert_eq!(trix.signal_period(), None);
+
+        // Triple smoothed EMA: 2, 2.375, 2.4375, 2.90625
+        assert_eq!(tuple(trix.next(2.0)), (0.0, None));
+        assert_eq!(tuple(trix.next(5.0)), (18.75, None));
+        assert_eq!(tuple(trix.next(1.0)), (2.632, None));
+        assert_eq!(tuple(trix.next(6.25)), (19.231, None));
+    }
+
+    #[test]
+    fn test_next_with_signal() {
+        let mut trix = Trix::with_signal(3, 2).unwrap();
+        assert_eq!(trix.signal_period(), Some(2));
+
+        assert_eq!(tuple(trix.next(2.0)), (0.0, Some(0.0)));
+        assert_eq!(tuple(trix.next(5.0)), (18.75, Some(12.5)));
+        assert_eq!(tuple(trix.next(1.0)), (2.632, Some(5.921)));
+        assert_eq!(tuple(trix.next(6.25)), (19.231, Some(14.794)));
+        assert_eq!(tuple(trix.next(4.0)), (14.516, Some(14.609)));
+        assert_eq!(tuple(trix.next(3.0)), (4.93, Some(8.156)));
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut trix = Trix::with_signal(3, 2).unwrap();
+
+        assert_eq!(tuple(trix.next(&Bar::new().close(2))), (0.0, Some(0.0)));
+        assert_eq!(tuple(trix.next(&Bar::new().close(5))), (18.75, Some(12.5)));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut trix = Trix::with_signal(3, 2).unwrap();
+
+        assert_eq!(tuple(trix.next(2.0)), (0.0, Some(0.0)));
+        assert_eq!(tuple(trix.next(5.0)), (18.75, Some(12.5)));
+
+        trix.reset();
+        assert_eq!(tuple(trix.next(2.0)), (0.0, Some(0.0)));
+        assert_eq!(tuple(trix.next(5.0)), (18.75, Some(12.5)));
+    }
+
+    #[test]
+    fn test_default() {
+        Trix::default();
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Trix::new(15).unwrap()), "TRIX(15)");
+        assert_eq!(
+            format!("{}", Trix::with_signal(15, 9).unwrap()),
+            "TRIX(15, 9)"
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use std::fmt;
+
+use crate::errors::{Result, TaError};
+use crate::{Close, Next, Period, Reset, RollingWindow};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Variable Index Dynamic Average (VIDYA).
+///
+/// Developed by Tushar Chande, an exponential moving average whose smoothing factor is
+/// scaled by the absolute value of the Chande Momentum Oscillator (CMO). The average
+/// follows prices quickly in a strong trend and flattens out when prices move sideways.
+///
+/// # Formula
+///
+/// VIDYA<sub>t</sub> = VIDYA<sub>t-1</sub> + α<sub>t</sub> * (p<sub>t</sub> - VIDYA<sub>t-1</sub>)
+///
+/// α<sub>t</sub> = 2 / (_period_ + 1) * |CMO<sub>t</sub>|
+///
+/// CMO<sub>t</sub> = (_up_ - _down_) / (_up_ + _down_)
+///
+/// Where:
+///
+/// * _p<sub>t</sub>_ - input value at a time period t
+/// * _up_ - sum of the price increases over the last _cmo_period_ changes
+/// * _down_ - sum of the absolute price decreases over the last _cmo_period_ changes
+///
+/// The first input is taken as is. When prices do not change, CMO is 0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods of the smoothing factor (integer greater than 0). Default is 9.
+/// * _cmo_period_ - number of price changes of CMO (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VariableIndexDynamicAverage;
+/// use ta::Next;
+///
+/// let mut vidya = VariableIndexDynamicAverage::new(3, 2).unwrap();
+/// assert_eq!(vidya.next(10.0), 10.0);
+/// assert_eq!(vidya.next(11.0), 10.5);
+/// assert_eq!(vidya.next(12.0), 11.25);
+/// assert_eq!(vidya.next(11.0), 11.25);
+/// assert_eq!(vidya.alpha(), Some(0.0));
+/// ```
+///
+/// # Links
+///
+/// * [Variable Index Dynamic Average, TradingView](https://www.tradingview.com/support/solutions/43000594684-variable-index-dynamic-average-vidya/)
+///
+#[doc(alias = "VIDYA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VariableIndexDynamicAverage {
+    period: usize,
+    k: f64,
+    changes: RollingWindow,
+    previous: Option<f64>,
+    current: f64,
+    alpha: Option<f64>,
+}
+
+impl VariableIndexDynamicAverage {
+    pub fn new(period: usize, cmo_period: usize) -> Result<Self> {
+        match period {
+            0 => Err(TaError::InvalidParameter),
+            _ => Ok(Self {
+                period,
+                k: 2.0 / (period + 1) as f64,
+                changes: RollingWindow::new(cmo_period)?,
+                previous: None,
+                current: 0.0,
+                alpha: None,
+            }),
+        }
+    }
+
+    pub fn cmo_period(&self) -> usize {
+        self.changes.capacity()
+    }
+
+    /// Returns the smoothing factor applied to the last input, `None` before the first input.
+    pub fn alpha(&self) -> Option<f64> {
+        self.alpha
+    }
+
+    fn cmo(&self) -> f64 {
+        let (up, down) = self.changes.iter().fold((0.0, 0.0), |(up, down), change| {
+            if change > 0.0 {
+                (up + change, down)
+            } else {
+                (up, down - change)
+            }
+        });
+
+        if up + down == 0.0 {
+            0.0
+        } else {
+            (up - down) / (up + down)
+        }
+    }
+}
+
+impl Period for VariableIndexDynamicAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for VariableIndexDynamicAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let alpha = match self.previous {
+            None => 1.0,
+            Some(previous) => {
+                self.changes.push(input - previous);
+                self.k * self.cmo().abs()
+            }
+        };
+
+        self.previous = Some(input);
+        self.alpha = Some(alpha);
+        self.current += alpha * (input - self.current);
+        self.current
+    }
+}
+
+impl<T: Close> Next<&T> for VariableIndexDynamicAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for VariableIndexDynamicAverage {
+    fn reset(&mut self) {
+        self.changes.clear();
+        self.previous = None;
+        self.current = 0.0;
+        self.alpha = None;
+    }
+}
+
+impl Default for VariableIndexDynamicAverage {
+    fn default() -> Self {
+        Self::new(9, 9).unwrap()
+    }
+}
+
+impl fmt::Display for VariableIndexDynamicAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VIDYA({}, {})", self.period, self.cmo_period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(VariableIndexDynamicAverage);
+
+    #[test]
+    fn test_new() {
+        assert!(VariableIndexDynamicAverage::new(0, 1).is_err());
+        assert!(VariableIndexDynamicAverage::new(1, 0).is_err());
+        assert!(VariableIndexDynamicAverage::new(1, 1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut vidya = VariableIndexDynamicAverage::new(3, 2).unwrap();
+        assert_eq!(vidya.alpha(), None);
+
+        let test_data = vec![
+            // input, vidya, alpha
+            (10.0, 10.0, 1.0),
+            (11.0, 10.5, 0.5),
+            (12.0, 11.25, 0.5),
+            (11.0, 11.25, 0.0),
+            (9.0, 10.125, 0.5),
+            (9.0, 9.563, 0.5),
+            (10.0, 9.781, 0.5),
+        ];
+
+        for (input, value, alpha) in test_data {
+            assert_eq!(round(vidya.next(input)), value);
+            assert_eq!(vidya.alpha(), Some(alpha));
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut vidya = VariableIndexDynamicAverage::new(3, 2).unwrap();
+
+        assert_eq!(vidya.next(&Bar::new().close(10)), 10.0);
+        assert_eq!(vidya.next(&Bar::new().close(11)), 10.5);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vidya = VariableIndexDynamicAverage::new(3, 2).unwrap();
+
+        vidya.next(10.0);
+        vidya.next(11.0);
+
+        vidya.reset();
+        assert_eq!(vidya.alpha(), None);
+        assert_eq!(vidya.next(12.0), 12.0);
+        assert_eq!(vidya.next(13.0), 12.5);
+    }
+
+    #[test]
+    fn test_default() {
+        VariableIndexDynamicAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vidya = VariableIndexDynamicAverage::new(9, 14).unwrap();
+        assert_eq!(format!("{}", vidya), "VIDYA(9, 14)");
+    }
+}
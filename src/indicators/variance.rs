@@ -0,0 +1,318 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::helpers::CompensatedSum;
+use crate::storage::{Heap, Inline, Storage};
+use crate::{Close, Next, Period, Reset, RollingWindow};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Estimator of the variance, used by [Variance], [StandardDeviation](crate::indicators::StandardDeviation)
+/// and [BollingerBands](crate::indicators::BollingerBands).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarianceEstimator {
+    /// Divides the sum of squared deviations by _n_.
+    Population,
+    /// Divides the sum of squared deviations by _n - 1_ (Bessel's correction).
+    Sample,
+}
+
+/// Variance (VAR).
+///
+/// Returns the variance of the last n values.
+///
+/// # Formula
+///
+/// * Population: _VAR_ = Σ(_x<sub>i</sub>_ - _μ_)<sup>2</sup> / _N_
+/// * Sample: _VAR_ = Σ(_x<sub>i</sub>_ - _μ_)<sup>2</sup> / (_N_ - 1)
+///
+/// Where:
+///
+/// * _μ_ - mean of the observed values.
+/// * _N_ - number of probes in observation.
+/// * _x<sub>i</sub>_ - i-th observed value from N elements observation.
+///
+/// The sample variance of a single value is 0.0.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0)
+/// * _estimator_ - see [VarianceEstimator]. Default is population.
+///
+/// The mean is derived from a compensated running sum and the sum of squared deviations
+/// is updated incrementally. Both are recomputed from the window once every _period_
+/// updates, so rounding errors do not accumulate over long-running streams, and as soon
+/// as the sum of squared deviations cancels out, e.g. when large values leave the window
+/// and small ones remain.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{Variance, VarianceEstimator};
+/// use ta::Next;
+///
+/// let mut var = Variance::new(3).unwrap();
+/// assert_eq!(var.next(10.0), 0.0);
+/// assert_eq!(var.next(20.0), 25.0);
+///
+/// let mut var = Variance::with_estimator(3, VarianceEstimator::Sample).unwrap();
+/// assert_eq!(var.next(10.0), 0.0);
+/// assert_eq!(var.next(20.0), 50.0);
+/// ```
+///
+/// # Links
+///
+/// * [Variance, Wikipedia](https://en.wikipedia.org/wiki/Variance)
+///
+#[doc(alias = "VAR")]
+pub type Variance = VarianceBase<Heap>;
+
+/// Variance (VAR) of the last `N` values, with `N` known at compile time.
+///
+/// Behaves exactly like [Variance], but the window is stored inline
+/// and no heap allocation is performed.
+pub type InlineVariance<const N: usize> = VarianceBase<Inline<N>>;
+
+/// Variance (VAR) generic over its [Storage].
+///
+/// See [Variance] and [InlineVariance].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VarianceBase<S: Storage> {
+    estimator: VarianceEstimator,
+    sum: CompensatedSum,
+    m: f64,
+    m2: f64,
+    // Sum of the absolute updates of m2 since it was recomputed
+    magnitude: f64,
+    updates: usize,
+    window: RollingWindow<S>,
+}
+
+impl<S: Storage> VarianceBase<S> {
+    pub(super) fn with_period(period: usize, estimator: VarianceEstimator) -> Result<Self> {
+        Ok(Self {
+            estimator,
+            sum: CompensatedSum::default(),
+            m: 0.0,
+            m2: 0.0,
+            magnitude: 0.0,
+            updates: 0,
+            window: RollingWindow::new(period)?,
+        })
+    }
+
+    pub fn estimator(&self) -> VarianceEstimator {
+        self.estimator
+    }
+
+    pub(super) fn mean(&self) -> f64 {
+        self.m
+    }
+
+    fn recompute(&mut self) {
+        let count = self.window.len() as f64;
+        self.sum = self.window.iter().collect();
+        self.m = self.sum.value() / count;
+        self.m2 = self.window.iter().map(|x| (x - self.m).powi(2)).sum();
+        self.magnitude = self.m2;
+        self.updates = 0;
+    }
+}
+
+impl Variance {
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_estimator(period, VarianceEstimator::Population)
+    }
+
+    pub fn with_estimator(period: usize, estimator: VarianceEstimator) -> Result<Self> {
+        Self::with_period(period, estimator)
+    }
+}
+
+impl<const N: usize> InlineVariance<N> {
+    pub fn new() -> Result<Self> {
+        Self::with_estimator(VarianceEstimator::Population)
+    }
+
+    pub fn with_estimator(estimator: VarianceEstimator) -> Result<Self> {
+        Self::with_period(N, estimator)
+    }
+}
+
+impl<S: Storage> Period for VarianceBase<S> {
+    fn period(&self) -> usize {
+        self.window.capacity()
+    }
+}
+
+impl<S: Storage> Next<f64> for VarianceBase<S> {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let old_m = self.m;
+        let count = self.window.len() as f64;
+        let update = match self.window.push(input) {
+            None => {
+                self.sum.add(input);
+                self.m = self.sum.value() / (count + 1.0);
+                (input - old_m) * (input - self.m)
+            }
+            Some(old_val) => {
+                self.sum.add(-old_val);
+                self.sum.add(input);
+                self.m = self.sum.value() / count;
+                self.updates += 1;
+                (input - old_val) * (input - self.m + old_val - old_m)
+            }
+        };
+        self.m2 += update;
+        self.magnitude += update.abs();
+
+        // The rounding errors of the updates are relative to their magnitude,
+        // so a sum of squared deviations much smaller than that is unreliable
+        if self.updates == self.window.capacity() || self.m2 < self.magnitude * 1e-6 {
+            self.recompute();
+        }
+
+        let count = self.window.len();
+        match self.estimator {
+            VarianceEstimator::Population => self.m2 / count as f64,
+            VarianceEstimator::Sample if count > 1 => self.m2 / (count - 1) as f64,
+            VarianceEstimator::Sample => 0.0,
+        }
+    }
+}
+
+impl<S: Storage, T: Close> Next<&T> for VarianceBase<S> {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl<S: Storage> Reset for VarianceBase<S> {
+    fn reset(&mut self) {
+        self.sum.clear();
+        self.m = 0.0;
+        self.m2 = 0.0;
+        self.magnitude = 0.0;
+        self.updates = 0;
+        self.window.clear();
+    }
+}
+
+impl Default for Variance {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl<const N: usize> Default for InlineVariance<N> {
+    fn default() -> Self {
+        Self::new().unwrap()
+    }
+}
+
+impl<S: Storage> fmt::Display for VarianceBase<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VAR({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(Variance);
+
+    mod inline {
+        use super::*;
+
+        type InlineVar = InlineVariance<9>;
+
+        test_indicator!(InlineVar);
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Variance::new(0).is_err());
+        assert!(Variance::with_estimator(0, VarianceEstimator::Sample).is_err());
+        assert!(Variance::new(1).is_ok());
+        assert!(InlineVariance::<0>::new().is_err());
+        assert!(InlineVariance::<1>::new().is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        fn check<S: Storage>(mut var: VarianceBase<S>) {
+            assert_eq!(var.estimator(), VarianceEstimator::Population);
+            assert_eq!(var.next(10.0), 0.0);
+            assert_eq!(var.next(20.0), 25.0);
+            assert_eq!(round(var.next(30.0)), 66.667);
+            assert_eq!(var.next(20.0), 50.0);
+            assert_eq!(var.next(10.0), 50.0);
+            assert_eq!(var.next(100.0), 1250.0);
+        }
+
+        check(Variance::new(4).unwrap());
+        check(InlineVariance::<4>::new().unwrap());
+    }
+
+    #[test]
+    fn test_next_sample() {
+        fn check<S: Storage>(mut var: VarianceBase<S>) {
+            assert_eq!(var.estimator(), VarianceEstimator::Sample);
+            assert_eq!(var.next(10.0), 0.0);
+            assert_eq!(var.next(20.0), 50.0);
+            assert_eq!(var.next(30.0), 100.0);
+            assert_eq!(round(var.next(20.0)), 66.667);
+            assert_eq!(round(var.next(10.0)), 66.667);
+            assert_eq!(round(var.next(100.0)), 1666.667);
+        }
+
+        check(Variance::with_estimator(4, VarianceEstimator::Sample).unwrap());
+        check(InlineVariance::<4>::with_estimator(VarianceEstimator::Sample).unwrap());
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        fn bar(close: f64) -> Bar {
+            Bar::new().close(close)
+        }
+
+        let mut var = Variance::new(2).unwrap();
+        assert_eq!(var.next(&bar(10.0)), 0.0);
+        assert_eq!(var.next(&bar(20.0)), 25.0);
+        assert_eq!(var.next(&bar(30.0)), 25.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut var = Variance::with_estimator(4, VarianceEstimator::Sample).unwrap();
+        assert_eq!(var.next(10.0), 0.0);
+        assert_eq!(var.next(20.0), 50.0);
+
+        var.reset();
+        assert_eq!(var.next(20.0), 0.0);
+        assert_eq!(var.next(30.0), 50.0);
+    }
+
+    #[test]
+    fn test_default() {
+        Variance::default();
+        InlineVariance::<9>::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let var = Variance::new(5).unwrap();
+        assert_eq!(format!("{}", var), "VAR(5)");
+
+        let var = InlineVariance::<5>::new().unwrap();
+        assert_eq!(format!("{}", var), "VAR(5)");
+    }
+}
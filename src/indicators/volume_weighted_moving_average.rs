@@ -0,0 +1,158 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::SimpleMovingAverage as Sma;
+use crate::{Close, Next, Period, Reset, Volume};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volume weighted moving average (VWMA).
+///
+/// An average of the close prices of the last _period_ bars, weighted by their volume,
+/// so the prices traded with the highest volume have the highest influence.
+///
+/// # Formula
+///
+/// VWMA = Σ(close * volume) / Σ(volume)
+///
+/// Both sums run over the last _period_ bars. When the sum of the volume is 0,
+/// the current close is returned.
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 20.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::VolumeWeightedMovingAverage;
+/// use ta::{DataItem, Next};
+///
+/// let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+///
+/// let di1 = DataItem::builder()
+///     .high(11.0).low(9.0).close(10.0).open(10.0).volume(100.0).build().unwrap();
+/// let di2 = DataItem::builder()
+///     .high(21.0).low(19.0).close(20.0).open(20.0).volume(300.0).build().unwrap();
+///
+/// assert_eq!(vwma.next(&di1), 10.0);
+/// assert_eq!(vwma.next(&di2), 17.5);
+/// ```
+///
+/// # Links
+///
+/// * [Volume Weighted Moving Average, TradingView](https://www.tradingview.com/support/solutions/43000592293-volume-weighted-moving-average-vwma/)
+///
+#[doc(alias = "VWMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct VolumeWeightedMovingAverage {
+    price_volume: Sma,
+    volume: Sma,
+}
+
+impl VolumeWeightedMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            price_volume: Sma::new(period)?,
+            volume: Sma::new(period)?,
+        })
+    }
+}
+
+impl Period for VolumeWeightedMovingAverage {
+    fn period(&self) -> usize {
+        self.volume.period()
+    }
+}
+
+impl<T: Close + Volume> Next<&T> for VolumeWeightedMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        // The ratio of the averages is the ratio of the sums over the same window
+        let price_volume = self.price_volume.next(input.close() * input.volume());
+        let volume = self.volume.next(input.volume());
+
+        if volume == 0.0 {
+            input.close()
+        } else {
+            price_volume / volume
+        }
+    }
+}
+
+impl Reset for VolumeWeightedMovingAverage {
+    fn reset(&mut self) {
+        self.price_volume.reset();
+        self.volume.reset();
+    }
+}
+
+impl Default for VolumeWeightedMovingAverage {
+    fn default() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl fmt::Display for VolumeWeightedMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "VWMA({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn test_new() {
+        assert!(VolumeWeightedMovingAverage::new(0).is_err());
+        assert!(VolumeWeightedMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_bar() {
+        let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+
+        let bar1 = Bar::new().close(10).volume(100.0);
+        let bar2 = Bar::new().close(20).volume(300.0);
+        let bar3 = Bar::new().close(30).volume(100.0);
+        let bar4 = Bar::new().close(40).volume(0.0);
+        let bar5 = Bar::new().close(50).volume(0.0);
+
+        assert_eq!(vwma.next(&bar1), 10.0);
+        assert_eq!(vwma.next(&bar2), 17.5);
+        assert_eq!(vwma.next(&bar3), 22.5);
+        assert_eq!(vwma.next(&bar4), 30.0);
+
+        // no volume in the window
+        assert_eq!(vwma.next(&bar5), 50.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+
+        let bar1 = Bar::new().close(10).volume(100.0);
+        let bar2 = Bar::new().close(20).volume(300.0);
+
+        assert_eq!(vwma.next(&bar1), 10.0);
+        assert_eq!(vwma.next(&bar2), 17.5);
+
+        vwma.reset();
+        assert_eq!(vwma.next(&bar2), 20.0);
+    }
+
+    #[test]
+    fn test_default() {
+        VolumeWeightedMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let vwma = VolumeWeightedMovingAverage::new(20).unwrap();
+        assert_eq!(format!("{}", vwma), "VWMA(20)");
+    }
+}
@@ -1,7 +1,8 @@
 use std::fmt;
 
-use crate::errors::{Result, TaError};
-use crate::{Close, Next, Period, Reset};
+use crate::errors::Result;
+use crate::storage::{Heap, Inline, Storage};
+use crate::{Close, Next, Period, Reset, RollingWindow};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -37,69 +38,77 @@ use serde::{Deserialize, Serialize};
 ///
 /// * [Weighted moving average, Wikipedia](https://en.wikipedia.org/wiki/Moving_average#Weighted_moving_average)
 ///
-
 #[doc(alias = "WMA")]
+pub type WeightedMovingAverage = WeightedMovingAverageBase<Heap>;
+
+/// Weighted moving average (WMA) with the period `N` known at compile time.
+///
+/// Behaves exactly like [WeightedMovingAverage], but the window is stored inline
+/// and no heap allocation is performed.
+pub type InlineWeightedMovingAverage<const N: usize> = WeightedMovingAverageBase<Inline<N>>;
+
+/// Weighted moving average (WMA) generic over its [Storage].
+///
+/// See [WeightedMovingAverage] and [InlineWeightedMovingAverage].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
-pub struct WeightedMovingAverage {
-    period: usize,
-    index: usize,
-    count: usize,
+pub struct WeightedMovingAverageBase<S: Storage> {
     weight: f64,
     sum: f64,
     sum_flat: f64,
-    deque: Box<[f64]>,
+    window: RollingWindow<S>,
+}
+
+impl<S: Storage> WeightedMovingAverageBase<S> {
+    fn with_period(period: usize) -> Result<Self> {
+        Ok(Self {
+            weight: 0.0,
+            sum: 0.0,
+            sum_flat: 0.0,
+            window: RollingWindow::new(period)?,
+        })
+    }
 }
 
 impl WeightedMovingAverage {
     pub fn new(period: usize) -> Result<Self> {
-        match period {
-            0 => Err(TaError::InvalidParameter),
-            _ => Ok(Self {
-                period,
-                index: 0,
-                count: 0,
-                weight: 0.0,
-                sum: 0.0,
-                sum_flat: 0.0,
-                deque: vec![0.0; period].into_boxed_slice(),
-            }),
-        }
+        Self::with_period(period)
+    }
+}
+
+impl<const N: usize> InlineWeightedMovingAverage<N> {
+    pub fn new() -> Result<Self> {
+        Self::with_period(N)
     }
 }
 
-impl Period for WeightedMovingAverage {
+impl<S: Storage> Period for WeightedMovingAverageBase<S> {
     fn period(&self) -> usize {
-        self.period
+        self.window.capacity()
     }
 }
 
-impl Next<f64> for WeightedMovingAverage {
+impl<S: Storage> Next<f64> for WeightedMovingAverageBase<S> {
     type Output = f64;
 
     fn next(&mut self, input: f64) -> Self::Output {
-        let old_val: f64 = self.deque[self.index];
-        self.deque[self.index] = input;
-
-        self.index = if self.index + 1 < self.period {
-            self.index + 1
-        } else {
-            0
+        let old_val = match self.window.push(input) {
+            None => {
+                self.weight = self.window.len() as f64;
+                self.sum += input * self.weight;
+                0.0
+            }
+            Some(old_val) => {
+                self.sum = self.sum - self.sum_flat + (input * self.weight);
+                old_val
+            }
         };
-
-        if self.count < self.period {
-            self.count += 1;
-            self.weight = self.count as f64;
-            self.sum += input * self.weight
-        } else {
-            self.sum = self.sum - self.sum_flat + (input * self.weight);
-        }
         self.sum_flat = self.sum_flat - old_val + input;
         self.sum / (self.weight * (self.weight + 1.0) / 2.0)
     }
 }
 
-impl<T: Close> Next<&T> for WeightedMovingAverage {
+impl<S: Storage, T: Close> Next<&T> for WeightedMovingAverageBase<S> {
     type Output = f64;
 
     fn next(&mut self, input: &T) -> Self::Output {
@@ -107,16 +116,12 @@ impl<T: Close> Next<&T> for WeightedMovingAverage {
     }
 }
 
-impl Reset for WeightedMovingAverage {
+impl<S: Storage> Reset for WeightedMovingAverageBase<S> {
     fn reset(&mut self) {
-        self.index = 0;
-        self.count = 0;
         self.weight = 0.0;
         self.sum = 0.0;
         self.sum_flat = 0.0;
-        for i in 0..self.period {
-            self.deque[i] = 0.0;
-        }
+        self.window.clear();
     }
 }
 
@@ -126,9 +131,15 @@ impl Default for WeightedMovingAverage {
     }
 }
 
-impl fmt::Display for WeightedMovingAverage {
+impl<const N: usize> Default for InlineWeightedMovingAverage<N> {
+    fn default() -> Self {
+        Self::new().unwrap()
+    }
+}
+
+impl<S: Storage> fmt::Display for WeightedMovingAverageBase<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "WMA({})", self.period)
+        write!(f, "WMA({})", self.period())
     }
 }
 
@@ -139,20 +150,33 @@ mod tests {
 
     test_indicator!(WeightedMovingAverage);
 
+    mod inline {
+        use super::*;
+
+        type InlineWma = InlineWeightedMovingAverage<9>;
+
+        test_indicator!(InlineWma);
+    }
+
     #[test]
     fn test_new() {
         assert!(WeightedMovingAverage::new(0).is_err());
         assert!(WeightedMovingAverage::new(1).is_ok());
+        assert!(InlineWeightedMovingAverage::<0>::new().is_err());
+        assert!(InlineWeightedMovingAverage::<1>::new().is_ok());
     }
 
     #[test]
     fn test_next() {
-        let mut wma = WeightedMovingAverage::new(3).unwrap();
+        fn check<S: Storage>(mut wma: WeightedMovingAverageBase<S>) {
+            assert_eq!(wma.next(12.0), 12.0);
+            assert_eq!(wma.next(3.0), 6.0); // (1*12 + 2*3) / 3 = 6.0
+            assert_eq!(wma.next(3.0), 4.5); // (1*12 + 2*3 + 3*3) / 6 = 4.5
+            assert_eq!(wma.next(5.0), 4.0); // (1*3 + 2*3 + 3*5) / 6 = 4.0
+        }
 
-        assert_eq!(wma.next(12.0), 12.0);
-        assert_eq!(wma.next(3.0), 6.0); // (1*12 + 2*3) / 3 = 6.0
-        assert_eq!(wma.next(3.0), 4.5); // (1*12 + 2*3 + 3*3) / 6 = 4.5
-        assert_eq!(wma.next(5.0), 4.0); // (1*3 + 2*3 + 3*5) / 6 = 4.0
+        check(WeightedMovingAverage::new(3).unwrap());
+        check(InlineWeightedMovingAverage::<3>::new().unwrap());
 
         let mut wma = WeightedMovingAverage::new(3).unwrap();
         let bar1 = Bar::new().close(2);
@@ -163,26 +187,33 @@ mod tests {
 
     #[test]
     fn test_reset() {
-        let mut wma = WeightedMovingAverage::new(5).unwrap();
-
-        assert_eq!(wma.next(4.0), 4.0);
-        wma.next(10.0);
-        wma.next(15.0);
-        wma.next(20.0);
-        assert_ne!(wma.next(4.0), 4.0);
+        fn check<S: Storage>(mut wma: WeightedMovingAverageBase<S>) {
+            assert_eq!(wma.next(4.0), 4.0);
+            wma.next(10.0);
+            wma.next(15.0);
+            wma.next(20.0);
+            assert_ne!(wma.next(4.0), 4.0);
+
+            wma.reset();
+            assert_eq!(wma.next(4.0), 4.0);
+        }
 
-        wma.reset();
-        assert_eq!(wma.next(4.0), 4.0);
+        check(WeightedMovingAverage::new(5).unwrap());
+        check(InlineWeightedMovingAverage::<5>::new().unwrap());
     }
 
     #[test]
     fn test_default() {
         WeightedMovingAverage::default();
+        InlineWeightedMovingAverage::<9>::default();
     }
 
     #[test]
     fn test_display() {
         let wma = WeightedMovingAverage::new(7).unwrap();
         assert_eq!(format!("{}", wma), "WMA(7)");
+
+        let wma = InlineWeightedMovingAverage::<7>::new().unwrap();
+        assert_eq!(format!("{}", wma), "WMA(7)");
     }
 }
@@ -0,0 +1,193 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::{Maximum, Minimum};
+use crate::{Close, High, Low, Next, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Williams %R.
+///
+/// Developed by Larry Williams, %R is a momentum indicator showing the level of the close
+/// relative to the highest high of the last _n_ periods. It is the inverse of the
+/// [fast stochastic oscillator](struct.FastStochastic.html), ranging from -100 to 0:
+/// readings above -20 are considered overbought and readings below -80 oversold.
+///
+/// # Formula
+///
+/// %R<sub>t</sub> = (H<sub>n</sub> - C<sub>t</sub>) / (H<sub>n</sub> - L<sub>n</sub>) * -100
+///
+/// Where:
+///
+/// * C<sub>t</sub> - close price of the current period
+/// * L<sub>n</sub> - lowest price for the last _n_ periods
+/// * H<sub>n</sub> - highest price for the last _n_ periods
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 14.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::WilliamsR;
+/// use ta::Next;
+///
+/// let mut wr = WilliamsR::new(5).unwrap();
+/// assert_eq!(wr.next(20.0), -50.0);
+/// assert_eq!(wr.next(30.0), 0.0);
+/// assert_eq!(wr.next(40.0), 0.0);
+/// assert_eq!(wr.next(35.0), -25.0);
+/// assert_eq!(wr.next(15.0), -100.0);
+/// ```
+///
+/// # Links
+///
+/// * [Williams %R, StockCharts](https://school.stockcharts.com/doku.php?id=technical_indicators:williams_r)
+///
+#[doc(alias = "%R")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WilliamsR {
+    period: usize,
+    minimum: Minimum,
+    maximum: Maximum,
+}
+
+impl WilliamsR {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            minimum: Minimum::new(period)?,
+            maximum: Maximum::new(period)?,
+        })
+    }
+}
+
+impl Period for WilliamsR {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Next<f64> for WilliamsR {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        let min = self.minimum.next(input);
+        let max = self.maximum.next(input);
+
+        if min == max {
+            // When only 1 input was given, than min and max are the same,
+            // therefore it makes sense to return the middle of the range
+            -50.0
+        } else {
+            (input - max) / (max - min) * 100.0
+        }
+    }
+}
+
+impl<T: High + Low + Close> Next<&T> for WilliamsR {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        let highest = self.maximum.next(input.high());
+        let lowest = self.minimum.next(input.low());
+        let close = input.close();
+
+        if highest == lowest {
+            // To avoid division by zero, return -50.0
+            -50.0
+        } else {
+            (close - highest) / (highest - lowest) * 100.0
+        }
+    }
+}
+
+impl Reset for WilliamsR {
+    fn reset(&mut self) {
+        self.minimum.reset();
+        self.maximum.reset();
+    }
+}
+
+impl Default for WilliamsR {
+    fn default() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl fmt::Display for WilliamsR {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "WILLR({})", self.period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(WilliamsR);
+
+    #[test]
+    fn test_new() {
+        assert!(WilliamsR::new(0).is_err());
+        assert!(WilliamsR::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next_with_f64() {
+        let mut wr = WilliamsR::new(3).unwrap();
+        assert_eq!(wr.next(0.0), -50.0);
+        assert_eq!(wr.next(200.0), 0.0);
+        assert_eq!(wr.next(100.0), -50.0);
+        assert_eq!(wr.next(120.0), -80.0);
+        assert_eq!(wr.next(115.0), -25.0);
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let test_data = vec![
+            // high, low , close, expected
+            (20.0, 20.0, 20.0, -50.0), // min = 20, max = 20
+            (30.0, 10.0, 25.0, -25.0), // min = 10, max = 30
+            (40.0, 20.0, 16.0, -80.0), // min = 10, max = 40
+            (35.0, 15.0, 19.0, -70.0), // min = 10, max = 40
+            (30.0, 20.0, 25.0, -60.0), // min = 15, max = 40
+            (35.0, 25.0, 30.0, -25.0), // min = 15, max = 35
+        ];
+
+        let mut wr = WilliamsR::new(3).unwrap();
+
+        for (high, low, close, expected) in test_data {
+            let input_bar = Bar::new().high(high).low(low).close(close);
+            assert_eq!(wr.next(&input_bar), expected);
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut indicator = WilliamsR::new(10).unwrap();
+        assert_eq!(indicator.next(10.0), -50.0);
+        assert_eq!(indicator.next(210.0), 0.0);
+        assert_eq!(indicator.next(10.0), -100.0);
+        assert_eq!(indicator.next(60.0), -75.0);
+
+        indicator.reset();
+        assert_eq!(indicator.next(10.0), -50.0);
+        assert_eq!(indicator.next(20.0), 0.0);
+        assert_eq!(indicator.next(12.5), -75.0);
+    }
+
+    #[test]
+    fn test_default() {
+        WilliamsR::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let indicator = WilliamsR::new(21).unwrap();
+        assert_eq!(format!("{}", indicator), "WILLR(21)");
+    }
+}
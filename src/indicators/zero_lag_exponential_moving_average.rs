@@ -0,0 +1,171 @@
+use std::fmt;
+
+use crate::errors::Result;
+use crate::indicators::ExponentialMovingAverage as Ema;
+use crate::{Close, Next, Period, Reset, RollingWindow};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Zero-lag exponential moving average (ZLEMA).
+///
+/// Developed by John Ehlers and Ric Way, ZLEMA removes most of the lag of an
+/// [EMA](struct.ExponentialMovingAverage.html) by adding to the input its change
+/// over the lag of the EMA, _(period - 1) / 2_ periods, before smoothing it.
+///
+/// # Formula
+///
+/// ZLEMA<sub>t</sub> = EMA(p<sub>t</sub> + (p<sub>t</sub> - p<sub>t-lag</sub>), _period_)
+///
+/// Where:
+///
+/// * _lag_ - (_period_ - 1) / 2, rounded down
+/// * _p<sub>t-lag</sub>_ - input _lag_ periods ago, the first input until there is one
+///
+/// # Parameters
+///
+/// * _period_ - number of periods (integer greater than 0). Default is 9.
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::ZeroLagExponentialMovingAverage;
+/// use ta::Next;
+///
+/// let mut zlema = ZeroLagExponentialMovingAverage::new(4).unwrap();
+/// assert_eq!(zlema.next(10.0), 10.0);
+/// assert_eq!(zlema.next(13.0), 12.4);
+/// ```
+///
+/// # Links
+///
+/// * [Zero lag exponential moving average, Wikipedia](https://en.wikipedia.org/wiki/Zero_lag_exponential_moving_average)
+///
+#[doc(alias = "ZLEMA")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct ZeroLagExponentialMovingAverage {
+    ema: Ema,
+    window: RollingWindow,
+}
+
+impl ZeroLagExponentialMovingAverage {
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            ema: Ema::new(period)?,
+            window: RollingWindow::new(period.saturating_sub(1) / 2 + 1)?,
+        })
+    }
+}
+
+impl Period for ZeroLagExponentialMovingAverage {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+impl Next<f64> for ZeroLagExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.window.push(input);
+        let lagged = self.window.oldest().unwrap();
+        self.ema.next(2.0 * input - lagged)
+    }
+}
+
+impl<T: Close> Next<&T> for ZeroLagExponentialMovingAverage {
+    type Output = f64;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.next(input.close())
+    }
+}
+
+impl Reset for ZeroLagExponentialMovingAverage {
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.window.clear();
+    }
+}
+
+impl Default for ZeroLagExponentialMovingAverage {
+    fn default() -> Self {
+        Self::new(9).unwrap()
+    }
+}
+
+impl fmt::Display for ZeroLagExponentialMovingAverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ZLEMA({})", self.period())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    test_indicator!(ZeroLagExponentialMovingAverage);
+
+    const INPUTS: [f64; 8] = [10.0, 13.0, 16.0, 14.0, 12.0, 15.0, 18.0, 17.0];
+
+    #[test]
+    fn test_new() {
+        assert!(ZeroLagExponentialMovingAverage::new(0).is_err());
+        assert!(ZeroLagExponentialMovingAverage::new(1).is_ok());
+    }
+
+    #[test]
+    fn test_next() {
+        let mut zlema = ZeroLagExponentialMovingAverage::new(4).unwrap();
+        let expected = [10.0, 12.4, 15.04, 13.824, 12.294, 14.577, 17.146, 16.688];
+        for (input, expected) in INPUTS.iter().zip(expected.iter()) {
+            assert_eq!(round(zlema.next(*input)), *expected);
+        }
+
+        let mut zlema = ZeroLagExponentialMovingAverage::new(5).unwrap();
+        let expected = [10.0, 12.0, 15.333, 15.222, 12.815, 13.877, 17.251, 17.834];
+        for (input, expected) in INPUTS.iter().zip(expected.iter()) {
+            assert_eq!(round(zlema.next(*input)), *expected);
+        }
+    }
+
+    #[test]
+    fn test_next_without_lag() {
+        // With a period of 1 the lag is 0 and the EMA returns the input
+        let mut zlema = ZeroLagExponentialMovingAverage::new(1).unwrap();
+        for input in INPUTS.iter() {
+            assert_eq!(zlema.next(*input), *input);
+        }
+    }
+
+    #[test]
+    fn test_next_with_bars() {
+        let mut zlema = ZeroLagExponentialMovingAverage::new(4).unwrap();
+
+        assert_eq!(zlema.next(&Bar::new().close(10)), 10.0);
+        assert_eq!(zlema.next(&Bar::new().close(13)), 12.4);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut zlema = ZeroLagExponentialMovingAverage::new(4).unwrap();
+
+        assert_eq!(zlema.next(10.0), 10.0);
+        assert_eq!(zlema.next(13.0), 12.4);
+
+        zlema.reset();
+        assert_eq!(zlema.next(13.0), 13.0);
+    }
+
+    #[test]
+    fn test_default() {
+        ZeroLagExponentialMovingAverage::default();
+    }
+
+    #[test]
+    fn test_display() {
+        let zlema = ZeroLagExponentialMovingAverage::new(7).unwrap();
+        assert_eq!(format!("{}", zlema), "ZLEMA(7)");
+    }
+}
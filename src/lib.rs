@@ -10,6 +10,9 @@
 //! Since `Next<T>` is a generic trait, most of the indicators can work with both input types: `f64` and more complex
 //! structures like [DataItem](struct.DataItem.html).
 //!
+//! The price an indicator picks from a data item (e.g. close for EMA) can be changed with
+//! [WithSource](struct.WithSource.html) and a [PriceSource](enum.PriceSource.html) or a closure.
+//!
 //! # Example
 //! ```
 //! use ta::indicators::ExponentialMovingAverage;
@@ -28,28 +31,59 @@
 //!
 //! * Trend
 //!   * [Exponential Moving Average (EMA)](crate::indicators::ExponentialMovingAverage)
+//!   * [Double Exponential Moving Average (DEMA)](crate::indicators::DoubleExponentialMovingAverage)
+//!   * [Triple Exponential Moving Average (TEMA)](crate::indicators::TripleExponentialMovingAverage)
 //!   * [Simple Moving Average (SMA)](crate::indicators::SimpleMovingAverage)
 //!   * [Weighted Moving Average (WMA)](crate::indicators::WeightedMovingAverage)
+//!   * [Smoothed Moving Average (SMMA)](crate::indicators::SmoothedMovingAverage)
+//!   * [Volume Weighted Moving Average (VWMA)](crate::indicators::VolumeWeightedMovingAverage)
+//!   * [Hull Moving Average (HMA)](crate::indicators::HullMovingAverage)
+//!   * [Zero-Lag Exponential Moving Average (ZLEMA)](crate::indicators::ZeroLagExponentialMovingAverage)
+//!   * [Arnaud Legoux Moving Average (ALMA)](crate::indicators::ArnaudLegouxMovingAverage)
+//!   * [Tillson T3 Moving Average (T3)](crate::indicators::T3MovingAverage)
+//!   * [McGinley Dynamic (MD)](crate::indicators::McGinleyDynamic)
+//!   * [Variable Index Dynamic Average (VIDYA)](crate::indicators::VariableIndexDynamicAverage)
+//!   * [Fractal Adaptive Moving Average (FRAMA)](crate::indicators::FractalAdaptiveMovingAverage)
 //! * Oscillators
 //!   * [Relative Strength Index (RSI)](indicators/struct.RelativeStrengthIndex.html)
 //!   * [Fast Stochastic](indicators/struct.FastStochastic.html)
 //!   * [Slow Stochastic](indicators/struct.SlowStochastic.html)
+//!   * [Full Stochastic](indicators/struct.FullStochastic.html)
+//!   * [Stochastic RSI (StochRSI)](indicators/struct.StochasticRsi.html)
+//!   * [Williams %R](indicators/struct.WilliamsR.html)
 //!   * [Moving Average Convergence Divergence (MACD)](indicators/struct.MovingAverageConvergenceDivergence.html)
 //!   * [Percentage Price Oscillator (PPO)](indicators/struct.PercentagePriceOscillator.html)
+//!   * [Triple Exponential Average Oscillator (TRIX)](indicators/struct.Trix.html)
 //!   * [Commodity Channel Index (CCI)](indicators/struct.CommodityChannelIndex.html)
 //!   * [Money Flow Index (MFI)](indicators/struct.MoneyFlowIndex.html)
+//!   * [Aroon](indicators/struct.Aroon.html)
 //! * Other
 //!   * [Standard Deviation (SD)](indicators/struct.StandardDeviation.html)
+//!   * [Variance (VAR)](indicators/struct.Variance.html)
 //!   * [Mean Absolute Deviation (MAD)](indicators/struct.MeanAbsoluteDeviation.html)
+//!   * [Median](crate::indicators::Median)
+//!   * [Median Absolute Deviation (MedAD)](crate::indicators::MedianAbsoluteDeviation)
 //!   * [Bollinger Bands (BB)](indicators/struct.BollingerBands.html)
+//!   * [Squeeze (TTM Squeeze)](indicators/struct.Squeeze.html)
 //!   * [Chandelier Exit (CE)](indicators/struct.ChandelierExit.html)
+//!   * [Supertrend](indicators/struct.Supertrend.html)
 //!   * [Keltner Channel (KC)](indicators/struct.KeltnerChannel.html)
+//!   * [Donchian Channel (DC)](indicators/struct.DonchianChannel.html)
 //!   * [Maximum](indicators/struct.Maximum.html)
 //!   * [Minimum](indicators/struct.Minimum.html)
+//!   * [ArgMaximum](indicators/struct.ArgMaximum.html)
+//!   * [ArgMinimum](indicators/struct.ArgMinimum.html)
 //!   * [True Range](indicators/struct.TrueRange.html)
 //!   * [Average True Range (ATR)](indicators/struct.AverageTrueRange.html)
+//!   * [Normalized Average True Range (NATR)](indicators/struct.NormalizedAverageTrueRange.html)
+//!   * [Average True Range Percent Rank (ATR_RANK)](indicators/struct.AverageTrueRangePercentRank.html)
 //!   * [Efficiency Ratio (ER)](indicators/struct.EfficiencyRatio.html)
 //!   * [Rate of Change (ROC)](indicators/struct.RateOfChange.html)
+//!   * [Rate of Change Percentage (ROCP)](indicators/struct.RateOfChangePercentage.html)
+//!   * [Rate of Change Ratio (ROCR)](indicators/struct.RateOfChangeRatio.html)
+//!   * [Rate of Change Ratio 100 scale (ROCR100)](indicators/struct.RateOfChangeRatio100.html)
+//!   * [Momentum (MOM)](indicators/struct.Momentum.html)
+//!   * [Logarithmic Return (LOGRET)](indicators/struct.LogReturn.html)
 //!   * [On Balance Volume (OBV)](indicators/struct.OnBalanceVolume.html)
 //!
 #[cfg(test)]
@@ -60,9 +94,16 @@ mod helpers;
 
 pub mod errors;
 pub mod indicators;
+pub mod storage;
 
 mod traits;
 pub use crate::traits::*;
 
 mod data_item;
 pub use crate::data_item::DataItem;
+
+mod window;
+pub use crate::window::RollingWindow;
+
+mod price_source;
+pub use crate::price_source::{PriceSource, Source, WithSource};
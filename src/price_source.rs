@@ -0,0 +1,255 @@
+use std::fmt;
+
+use crate::{Close, High, Low, Next, Open, Period, Reset};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Extracts the price, that an indicator consumes, from a data item of type `T`.
+///
+/// It is implemented by [PriceSource] and by closures `Fn(&T) -> f64`.
+pub trait Source<T> {
+    fn price(&self, input: &T) -> f64;
+}
+
+impl<T, F: Fn(&T) -> f64> Source<T> for F {
+    fn price(&self, input: &T) -> f64 {
+        self(input)
+    }
+}
+
+/// Price of a data item used as the input of an indicator.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PriceSource {
+    /// Open price.
+    Open,
+    /// High price.
+    High,
+    /// Low price.
+    Low,
+    /// Close price, used by default.
+    #[default]
+    Close,
+    /// Median price: (high + low) / 2.
+    HL2,
+    /// Typical price: (high + low + close) / 3.
+    HLC3,
+    /// Average price: (open + high + low + close) / 4.
+    OHLC4,
+    /// Weighted close price: (high + low + 2 * close) / 4.
+    WeightedClose,
+}
+
+impl PriceSource {
+    /// Returns the price of the data item.
+    pub fn price<T: Open + High + Low + Close>(&self, input: &T) -> f64 {
+        match self {
+            Self::Open => input.open(),
+            Self::OHLC4 => (input.open() + input.high() + input.low() + input.close()) / 4.0,
+            _ => self.price_without_open(input).unwrap_or(f64::NAN),
+        }
+    }
+
+    /// Returns whether the price is computed from the open price of a data item.
+    pub fn uses_open(&self) -> bool {
+        matches!(self, Self::Open | Self::OHLC4)
+    }
+
+    /// Returns the price of a data item without an open price, `None` if the price
+    /// [uses the open price](Self::uses_open).
+    pub(crate) fn price_without_open<T: High + Low + Close>(&self, input: &T) -> Option<f64> {
+        match self {
+            Self::Open | Self::OHLC4 => None,
+            Self::High => Some(input.high()),
+            Self::Low => Some(input.low()),
+            Self::Close => Some(input.close()),
+            Self::HL2 => Some((input.high() + input.low()) / 2.0),
+            Self::HLC3 => Some((input.high() + input.low() + input.close()) / 3.0),
+            Self::WeightedClose => Some((input.high() + input.low() + 2.0 * input.close()) / 4.0),
+        }
+    }
+}
+
+impl<T: Open + High + Low + Close> Source<T> for PriceSource {
+    fn price(&self, input: &T) -> f64 {
+        PriceSource::price(self, input)
+    }
+}
+
+/// Feeds an indicator with the price selected by a [Source].
+///
+/// Any indicator implementing `Next<f64>` can consume data items through the wrapper,
+/// regardless of the price it picks by itself (e.g. close for EMA or high for Maximum).
+///
+/// # Example
+///
+/// ```
+/// use ta::indicators::{ExponentialMovingAverage, Maximum};
+/// use ta::{DataItem, Next, Open, PriceSource, WithSource};
+///
+/// let bar = DataItem::builder()
+///     .open(10.0).high(14.0).low(8.0).close(12.0).volume(1.0).build().unwrap();
+///
+/// // EMA of the median price
+/// let mut ema = WithSource::new(ExponentialMovingAverage::new(3).unwrap(), PriceSource::HL2);
+/// assert_eq!(ema.next(&bar), 11.0);
+///
+/// // Highest close
+/// let mut max = WithSource::new(Maximum::new(3).unwrap(), PriceSource::Close);
+/// assert_eq!(max.next(&bar), 12.0);
+///
+/// // Any closure works as a source
+/// let mut max = WithSource::new(Maximum::new(3).unwrap(), |bar: &DataItem| bar.open());
+/// assert_eq!(max.next(&bar), 10.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct WithSource<I, S = PriceSource> {
+    indicator: I,
+    source: S,
+}
+
+impl<I, S> WithSource<I, S> {
+    pub fn new(indicator: I, source: S) -> Self {
+        Self { indicator, source }
+    }
+
+    pub fn indicator(&self) -> &I {
+        &self.indicator
+    }
+
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+}
+
+impl<I: Next<f64>, S> Next<f64> for WithSource<I, S> {
+    type Output = I::Output;
+
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.indicator.next(input)
+    }
+}
+
+impl<T, I: Next<f64>, S: Source<T>> Next<&T> for WithSource<I, S> {
+    type Output = I::Output;
+
+    fn next(&mut self, input: &T) -> Self::Output {
+        self.indicator.next(self.source.price(input))
+    }
+}
+
+impl<I: Reset, S> Reset for WithSource<I, S> {
+    fn reset(&mut self) {
+        self.indicator.reset();
+    }
+}
+
+impl<I: Period, S> Period for WithSource<I, S> {
+    fn period(&self) -> usize {
+        self.indicator.period()
+    }
+}
+
+impl<I: Default> Default for WithSource<I, PriceSource> {
+    fn default() -> Self {
+        Self::new(I::default(), PriceSource::default())
+    }
+}
+
+impl<I: fmt::Display, S> fmt::Display for WithSource<I, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.indicator.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ExponentialMovingAverage, Maximum};
+    use crate::test_helper::*;
+
+    type SourcedEma = WithSource<ExponentialMovingAverage>;
+
+    test_indicator!(SourcedEma);
+
+    fn bar() -> Bar {
+        Bar::new().open(2.0).high(6.0).low(1.0).close(5.0)
+    }
+
+    #[test]
+    fn test_price() {
+        let bar = bar();
+
+        assert_eq!(PriceSource::Open.price(&bar), 2.0);
+        assert_eq!(PriceSource::High.price(&bar), 6.0);
+        assert_eq!(PriceSource::Low.price(&bar), 1.0);
+        assert_eq!(PriceSource::Close.price(&bar), 5.0);
+        assert_eq!(PriceSource::HL2.price(&bar), 3.5);
+        assert_eq!(PriceSource::HLC3.price(&bar), 4.0);
+        assert_eq!(PriceSource::OHLC4.price(&bar), 3.5);
+        assert_eq!(PriceSource::WeightedClose.price(&bar), 4.25);
+    }
+
+    #[test]
+    fn test_uses_open() {
+        assert!(PriceSource::Open.uses_open());
+        assert!(PriceSource::OHLC4.uses_open());
+        assert!(!PriceSource::Close.uses_open());
+        assert!(!PriceSource::HLC3.uses_open());
+
+        assert_eq!(PriceSource::OHLC4.price_without_open(&bar()), None);
+        assert_eq!(PriceSource::HLC3.price_without_open(&bar()), Some(4.0));
+    }
+
+    #[test]
+    fn test_closure() {
+        let source = |bar: &Bar| bar.high() - bar.low();
+        assert_eq!(source.price(&bar()), 5.0);
+    }
+
+    #[test]
+    fn test_next() {
+        let mut max = WithSource::new(Maximum::new(2).unwrap(), PriceSource::Close);
+
+        assert_eq!(max.next(&bar()), 5.0);
+        assert_eq!(max.next(&Bar::new().high(9.0).close(3.0)), 5.0);
+        assert_eq!(max.next(&Bar::new().high(9.0).close(4.0)), 4.0);
+        assert_eq!(max.next(7.0), 7.0);
+    }
+
+    #[test]
+    fn test_next_with_closure() {
+        let mut ema = WithSource::new(ExponentialMovingAverage::new(3).unwrap(), |bar: &Bar| {
+            bar.open()
+        });
+
+        assert_eq!(ema.next(&bar()), 2.0);
+        assert_eq!(ema.next(&Bar::new().open(4.0)), 3.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ema = WithSource::new(ExponentialMovingAverage::new(3).unwrap(), PriceSource::HL2);
+
+        assert_eq!(ema.next(&bar()), 3.5);
+        assert_eq!(ema.next(&Bar::new().high(8.0).low(7.0)), 5.5);
+
+        ema.reset();
+        assert_eq!(ema.next(&Bar::new().high(8.0).low(7.0)), 7.5);
+    }
+
+    #[test]
+    fn test_default() {
+        let ema = SourcedEma::default();
+        assert_eq!(ema.source(), &PriceSource::Close);
+        assert_eq!(ema.indicator().period(), 9);
+    }
+
+    #[test]
+    fn test_display() {
+        let ema = WithSource::new(ExponentialMovingAverage::new(7).unwrap(), PriceSource::HL2);
+        assert_eq!(format!("{}", ema), "EMA(7)");
+        assert_eq!(ema.period(), 7);
+    }
+}
@@ -0,0 +1,152 @@
+//! Storage backends for windowed indicators.
+//!
+//! Indicators that keep the last _period_ values (e.g. [SMA](crate::indicators::SimpleMovingAverage)
+//! or [Maximum](crate::indicators::Maximum)) are generic over the place where these values live:
+//!
+//! * [Heap] - the period is defined at runtime and the window is allocated on the heap.
+//! * [Inline] - the period is a const generic and the window is stored inline in an array,
+//!   so no heap allocation is performed.
+//!
+//! Positions in a buffer are wrapped around its capacity by [Storage::get] and [Storage::set].
+//! The inline storage wraps them around the const `N`, so the compiler can prove that every
+//! position is in range and no bounds checks are left in the hot path.
+//!
+//! # Example
+//!
+//! ```
+//! use ta::indicators::{InlineSimpleMovingAverage, SimpleMovingAverage};
+//! use ta::Next;
+//!
+//! let mut heap_sma = SimpleMovingAverage::new(3).unwrap();
+//! let mut inline_sma = InlineSimpleMovingAverage::<3>::new().unwrap();
+//!
+//! for input in &[10.0, 11.0, 12.0, 13.0] {
+//!     assert_eq!(heap_sma.next(*input), inline_sma.next(*input));
+//! }
+//! ```
+use std::fmt::Debug;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Defines how a windowed indicator stores its values.
+pub trait Storage {
+    /// Fixed size buffer of values of type `T`.
+    type Buffer<T: Copy + Debug>: AsRef<[T]> + AsMut<[T]> + Clone + Debug;
+
+    /// Creates a buffer of the given length, filled with `value`.
+    ///
+    /// Storages with a fixed size may ignore `len`, so the length of the
+    /// returned buffer should be checked when it matters.
+    fn buffer<T: Copy + Debug>(len: usize, value: T) -> Self::Buffer<T>;
+
+    /// Returns the number of values in the buffer.
+    fn capacity<T: Copy + Debug>(buffer: &Self::Buffer<T>) -> usize {
+        buffer.as_ref().len()
+    }
+
+    /// Returns the value at `position`, wrapped around the capacity of the buffer.
+    fn get<T: Copy + Debug>(buffer: &Self::Buffer<T>, position: usize) -> T {
+        let values = buffer.as_ref();
+        values[position % values.len()]
+    }
+
+    /// Replaces the value at `position`, wrapped around the capacity of the buffer.
+    fn set<T: Copy + Debug>(buffer: &mut Self::Buffer<T>, position: usize, value: T) {
+        let values = buffer.as_mut();
+        let len = values.len();
+        values[position % len] = value;
+    }
+}
+
+/// Heap allocated storage, the period is defined at runtime.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Heap;
+
+impl Storage for Heap {
+    type Buffer<T: Copy + Debug> = Box<[T]>;
+
+    fn buffer<T: Copy + Debug>(len: usize, value: T) -> Self::Buffer<T> {
+        vec![value; len].into_boxed_slice()
+    }
+
+    fn get<T: Copy + Debug>(buffer: &Self::Buffer<T>, position: usize) -> T {
+        buffer[wrap(position, buffer.len())]
+    }
+
+    fn set<T: Copy + Debug>(buffer: &mut Self::Buffer<T>, position: usize, value: T) {
+        let position = wrap(position, buffer.len());
+        buffer[position] = value;
+    }
+}
+
+/// Wraps `position` around `len`, skipping the division for positions already in range.
+#[inline]
+fn wrap(position: usize, len: usize) -> usize {
+    if position < len {
+        position
+    } else {
+        position % len
+    }
+}
+
+/// Inline storage, the period `N` is known at compile time.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct Inline<const N: usize>;
+
+impl<const N: usize> Storage for Inline<N> {
+    type Buffer<T: Copy + Debug> = [T; N];
+
+    fn buffer<T: Copy + Debug>(_len: usize, value: T) -> Self::Buffer<T> {
+        [value; N]
+    }
+
+    fn capacity<T: Copy + Debug>(_buffer: &Self::Buffer<T>) -> usize {
+        N
+    }
+
+    fn get<T: Copy + Debug>(buffer: &Self::Buffer<T>, position: usize) -> T {
+        buffer[position % N]
+    }
+
+    fn set<T: Copy + Debug>(buffer: &mut Self::Buffer<T>, position: usize, value: T) {
+        buffer[position % N] = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heap_buffer() {
+        let buffer = Heap::buffer(3, 1.5);
+        assert_eq!(buffer.as_ref(), &[1.5, 1.5, 1.5]);
+    }
+
+    #[test]
+    fn test_inline_buffer() {
+        let buffer = Inline::<2>::buffer(2, 0usize);
+        assert_eq!(buffer, [0, 0]);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        fn check<S: Storage>() {
+            let mut buffer = S::buffer(3, 0usize);
+            assert_eq!(S::capacity(&buffer), 3);
+
+            for position in 0..7 {
+                S::set(&mut buffer, position, position);
+            }
+            assert_eq!(buffer.as_ref(), &[6, 4, 5]);
+            assert_eq!(S::get(&buffer, 1), 4);
+            assert_eq!(S::get(&buffer, 5), 5);
+        }
+
+        check::<Heap>();
+        check::<Inline<3>>();
+    }
+}
@@ -0,0 +1,287 @@
+use crate::errors::{Result, TaError};
+use crate::storage::{Heap, Storage};
+
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Rolling window over the last _capacity_ values.
+///
+/// It is a circular buffer used by windowed indicators such as
+/// [SMA](crate::indicators::SimpleMovingAverage) or [Maximum](crate::indicators::Maximum).
+/// When the window is full, pushing a new value evicts the oldest one and returns it,
+/// so that the indicator can update its state incrementally.
+///
+/// The values are stored in a [Storage], on the heap by default.
+///
+/// # Example
+///
+/// A custom indicator that sums the last _n_ values:
+///
+/// ```
+/// use ta::{Next, RollingWindow};
+///
+/// struct MovingSum {
+///     window: RollingWindow,
+///     sum: f64,
+/// }
+///
+/// impl Next<f64> for MovingSum {
+///     type Output = f64;
+///
+///     fn next(&mut self, input: f64) -> f64 {
+///         let evicted = self.window.push(input).unwrap_or(0.0);
+///         self.sum += input - evicted;
+///         self.sum
+///     }
+/// }
+///
+/// let mut sum = MovingSum { window: RollingWindow::new(3).unwrap(), sum: 0.0 };
+/// assert_eq!(sum.next(1.0), 1.0);
+/// assert_eq!(sum.next(2.0), 3.0);
+/// assert_eq!(sum.next(3.0), 6.0);
+/// assert_eq!(sum.next(4.0), 9.0);
+/// assert_eq!(sum.window.iter().collect::<Vec<_>>(), vec![2.0, 3.0, 4.0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingWindow<S: Storage = Heap> {
+    index: usize,
+    len: usize,
+    buffer: S::Buffer<f64>,
+}
+
+impl<S: Storage> RollingWindow<S> {
+    /// Creates an empty window for the given number of values (integer greater than 0).
+    ///
+    /// For [Inline](crate::storage::Inline) storage the capacity must be equal to `N`.
+    pub fn new(capacity: usize) -> Result<Self> {
+        let buffer = S::buffer(capacity, 0.0);
+        if capacity == 0 || buffer.as_ref().len() != capacity {
+            return Err(TaError::InvalidParameter);
+        }
+
+        Ok(Self {
+            index: 0,
+            len: 0,
+            buffer,
+        })
+    }
+
+    /// Pushes a new value into the window.
+    ///
+    /// Returns the evicted value, if the window was full.
+    pub fn push(&mut self, value: f64) -> Option<f64> {
+        let capacity = self.capacity();
+
+        let evicted = if self.len == capacity {
+            Some(S::get(&self.buffer, self.index))
+        } else {
+            self.len += 1;
+            None
+        };
+        S::set(&mut self.buffer, self.index, value);
+
+        self.index = if self.index + 1 < capacity {
+            self.index + 1
+        } else {
+            0
+        };
+
+        evicted
+    }
+
+    /// Returns the value, which is `i` positions after the oldest value in the window.
+    pub fn get(&self, i: usize) -> Option<f64> {
+        if i < self.len {
+            Some(S::get(&self.buffer, self.oldest_index() + i))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the oldest value, which is evicted by the next push when the window is full.
+    pub fn oldest(&self) -> Option<f64> {
+        self.get(0)
+    }
+
+    /// Returns the most recently pushed value.
+    pub fn newest(&self) -> Option<f64> {
+        self.len.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Iterates over the values in chronological order, from the oldest to the newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = f64> + '_ {
+        let buffer = self.buffer.as_ref();
+        let (older, newer) = if self.is_full() {
+            (&buffer[self.index..], &buffer[..self.index])
+        } else {
+            (&buffer[..self.len], &buffer[..0])
+        };
+
+        older.iter().chain(newer.iter()).copied()
+    }
+
+    /// Returns the maximum number of values in the window.
+    pub fn capacity(&self) -> usize {
+        S::capacity(&self.buffer)
+    }
+
+    /// Returns the number of values in the window.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the window contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the next push evicts the oldest value.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Removes all values from the window.
+    pub fn clear(&mut self) {
+        self.index = 0;
+        self.len = 0;
+        for value in self.buffer.as_mut() {
+            *value = 0.0;
+        }
+    }
+
+    fn oldest_index(&self) -> usize {
+        if self.is_full() {
+            self.index
+        } else {
+            0
+        }
+    }
+}
+
+// The window is (de)serialized through a plain sequence of values, so that it works
+// with any storage, including inline arrays of arbitrary size.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct RawRollingWindow {
+    index: usize,
+    len: usize,
+    buffer: Vec<f64>,
+}
+
+#[cfg(feature = "serde")]
+impl<S: Storage> Serialize for RollingWindow<S> {
+    fn serialize<Ser: Serializer>(
+        &self,
+        serializer: Ser,
+    ) -> std::result::Result<Ser::Ok, Ser::Error> {
+        let raw = RawRollingWindow {
+            index: self.index,
+            len: self.len,
+            buffer: self.buffer.as_ref().to_vec(),
+        };
+        raw.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: Storage> Deserialize<'de> for RollingWindow<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let raw = RawRollingWindow::deserialize(deserializer)?;
+        let mut window = Self::new(raw.buffer.len()).map_err(D::Error::custom)?;
+        // Until the window is full, the next value is written right after the last one
+        let capacity = raw.buffer.len();
+        if raw.index >= capacity
+            || raw.len > capacity
+            || (raw.len < capacity && raw.index != raw.len)
+        {
+            return Err(D::Error::custom(TaError::DataItemInvalid));
+        }
+
+        window.buffer.as_mut().copy_from_slice(&raw.buffer);
+        window.index = raw.index;
+        window.len = raw.len;
+        Ok(window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::Inline;
+
+    fn values<S: Storage>(window: &RollingWindow<S>) -> Vec<f64> {
+        window.iter().collect()
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(RollingWindow::<Heap>::new(0).is_err());
+        assert!(RollingWindow::<Heap>::new(1).is_ok());
+        assert!(RollingWindow::<Inline<0>>::new(0).is_err());
+        assert!(RollingWindow::<Inline<3>>::new(2).is_err());
+        assert!(RollingWindow::<Inline<3>>::new(3).is_ok());
+    }
+
+    #[test]
+    fn test_push() {
+        fn check<S: Storage>(mut window: RollingWindow<S>) {
+            assert_eq!(window.push(1.0), None);
+            assert_eq!(window.push(2.0), None);
+            assert_eq!(window.push(3.0), None);
+            assert!(window.is_full());
+            assert_eq!(window.push(4.0), Some(1.0));
+            assert_eq!(window.push(5.0), Some(2.0));
+            assert_eq!(window.len(), 3);
+        }
+
+        check(RollingWindow::<Heap>::new(3).unwrap());
+        check(RollingWindow::<Inline<3>>::new(3).unwrap());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut window: RollingWindow = RollingWindow::new(3).unwrap();
+        assert!(values(&window).is_empty());
+
+        window.push(1.0);
+        window.push(2.0);
+        assert_eq!(values(&window), vec![1.0, 2.0]);
+
+        window.push(3.0);
+        window.push(4.0);
+        assert_eq!(values(&window), vec![2.0, 3.0, 4.0]);
+        assert_eq!(window.iter().rev().collect::<Vec<_>>(), vec![4.0, 3.0, 2.0]);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut window: RollingWindow = RollingWindow::new(2).unwrap();
+        assert_eq!(window.oldest(), None);
+        assert_eq!(window.newest(), None);
+
+        window.push(1.0);
+        assert_eq!(window.oldest(), Some(1.0));
+        assert_eq!(window.newest(), Some(1.0));
+
+        window.push(2.0);
+        window.push(3.0);
+        assert_eq!(window.get(0), Some(2.0));
+        assert_eq!(window.get(1), Some(3.0));
+        assert_eq!(window.get(2), None);
+        assert_eq!(window.oldest(), Some(2.0));
+        assert_eq!(window.newest(), Some(3.0));
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut window: RollingWindow = RollingWindow::new(2).unwrap();
+        window.push(1.0);
+        window.push(2.0);
+        window.push(3.0);
+
+        window.clear();
+        assert!(window.is_empty());
+        assert_eq!(window.push(4.0), None);
+        assert_eq!(values(&window), vec![4.0]);
+    }
+}
@@ -19,5 +19,82 @@ mod test {
 
             assert_eq!(deserialized.next(2.0), macd.next(2.0));
         }
+
+        #[test]
+        fn test_serde_window() {
+            use ta::RollingWindow;
+
+            let mut window: RollingWindow = RollingWindow::new(3).unwrap();
+            window.push(1.0);
+            window.push(2.0);
+            let bytes = bincode::serialize(&window).unwrap();
+            let mut deserialized: RollingWindow = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(deserialized.push(3.0), window.push(3.0));
+            assert!(deserialized.iter().eq(window.iter()));
+
+            // Write position does not follow the values of a window that is not full
+            let bytes = bincode::serialize(&(0usize, 1usize, vec![5.0, 0.0, 0.0])).unwrap();
+            assert!(bincode::deserialize::<RollingWindow>(&bytes).is_err());
+            // Empty window with a write position
+            let bytes = bincode::serialize(&(1usize, 0usize, vec![0.0, 0.0, 0.0])).unwrap();
+            assert!(bincode::deserialize::<RollingWindow>(&bytes).is_err());
+            // A full window may be written at any position
+            let bytes = bincode::serialize(&(1usize, 3usize, vec![1.0, 2.0, 3.0])).unwrap();
+            let deserialized: RollingWindow = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(deserialized.iter().collect::<Vec<_>>(), vec![2.0, 3.0, 1.0]);
+        }
+
+        #[test]
+        fn test_serde_extremes() {
+            use ta::indicators::{InlineMaximum, InlineMinimum, Maximum, Minimum};
+
+            let mut max = InlineMaximum::<64>::new().unwrap();
+            let mut min = Minimum::new(3).unwrap();
+            for input in &[3.0, 5.0, 1.0, 4.0, 2.0] {
+                max.next(*input);
+                min.next(*input);
+            }
+
+            let bytes = bincode::serialize(&max).unwrap();
+            let mut deserialized: InlineMaximum<64> = bincode::deserialize(&bytes).unwrap();
+            assert_eq!((deserialized.index(), deserialized.age()), (1, 3));
+            assert_eq!(deserialized.next(0.0), max.next(0.0));
+
+            let bytes = bincode::serialize(&min).unwrap();
+            let mut deserialized: Minimum = bincode::deserialize(&bytes).unwrap();
+            assert_eq!((deserialized.index(), deserialized.age()), (2, 2));
+            assert_eq!(deserialized.next(6.0), min.next(6.0));
+            assert_eq!(deserialized.next(6.0), min.next(6.0));
+            assert_eq!(deserialized.index(), min.index());
+
+            // More values in the window than inputs
+            let bytes = bincode::serialize(&(1usize, (0usize, 2usize, vec![1.0, 2.0]))).unwrap();
+            assert!(bincode::deserialize::<Maximum>(&bytes).is_err());
+            // Window of another capacity than the inline storage
+            let bytes = bincode::serialize(&(2usize, (0usize, 2usize, vec![1.0, 2.0]))).unwrap();
+            assert!(bincode::deserialize::<InlineMinimum<3>>(&bytes).is_err());
+        }
+
+        #[test]
+        fn test_serde_median() {
+            use ta::indicators::{InlineMedian, InlineMedianAbsoluteDeviation};
+
+            let mut median = InlineMedian::<64>::new().unwrap();
+            let mut mad = InlineMedianAbsoluteDeviation::<64>::new().unwrap();
+            for input in &[3.0, 5.0, 1.0, 4.0] {
+                median.next(*input);
+                mad.next(*input);
+            }
+
+            let bytes = bincode::serialize(&median).unwrap();
+            let mut deserialized: InlineMedian<64> = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(deserialized.next(2.0), median.next(2.0));
+            assert_eq!(deserialized.next(0.0), median.next(0.0));
+
+            let bytes = bincode::serialize(&mad).unwrap();
+            let mut deserialized: InlineMedianAbsoluteDeviation<64> =
+                bincode::deserialize(&bytes).unwrap();
+            assert_eq!(deserialized.next(2.0), mad.next(2.0));
+        }
     }
 }